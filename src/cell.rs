@@ -1,18 +1,24 @@
-use crate::coord::{ConversionMethod, Coordinate, Crs, convert_line_to_bng, convert_to_bng};
+use crate::coord::{
+    ConversionMethod, Coordinate, Crs, convert_line_to_bng, convert_to_bng, convert_to_wgs84,
+    preferred_method,
+};
 use crate::error::N3gbError;
 use crate::geom::create_hexagon;
 use crate::index::{
-    CELL_RADIUS, decode_hex_identifier, generate_hex_identifier, offset_to_cube, point_to_row_col,
-    row_col_to_center,
+    CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, HexDirection, IdentifierPrecision, center_to_row_col,
+    decode_hex_identifier, generate_hex_identifier, neighbor_row_cols, offset_to_cube, pack_key,
+    point_to_row_col, row_col_to_center, scale_coordinate,
 };
 use crate::io::arrow::HexCellsToArrow;
 use crate::io::parquet::HexCellsToGeoParquet;
 use arrow_array::RecordBatch;
-use geo::Centroid;
-use geo_types::{Geometry, LineString, Point, Polygon};
+use geo::orient::{Direction, Orient};
+use geo::{Area, Centroid, Contains, Distance, Geodesic};
+use geo_types::{Coord, Geometry, LineString, Point, Polygon, Rect, Triangle};
 use geoarrow_array::array::{PointArray, PolygonArray};
 use std::collections::HashSet;
 use std::path::Path;
+use wkb::writer::{WriteOptions, polygon_wkb_size, write_polygon};
 
 /// A single hexagonal cell in the n3gb spatial indexing system.
 ///
@@ -71,8 +77,94 @@ impl HexCell {
         }
     }
 
+    /// Create a HexCell from its components, checking that they are consistent.
+    ///
+    /// Intended for deserialization, where a cell is rebuilt from stored fields
+    /// rather than recomputed from a coordinate. The ID must decode to `center`
+    /// (to the identifier's precision, millimetres or whole metres) and
+    /// `zoom_level`, and `(row, col)` must be the grid address whose center is
+    /// `center`.
+    ///
+    /// # Arguments
+    /// * `id` - The encoded hex identifier for the cell.
+    /// * `center` - The cell center point in British National Grid coordinates.
+    /// * `zoom_level` - The zoom level (0-15) of the cell.
+    /// * `row` - The row index in the hexagonal grid.
+    /// * `col` - The column index in the hexagonal grid.
+    ///
+    /// # Returns
+    /// A `HexCell` with the given field values.
+    ///
+    /// # Errors
+    /// Returns any error from [`decode_hex_identifier`] if `id` cannot be decoded,
+    /// [`N3gbError::InvalidZoomLevel`] if `zoom_level` is out of range,
+    /// [`N3gbError::ZoomLevelMismatch`] if the ID encodes a different zoom level, and
+    /// [`N3gbError::InconsistentCell`] if the ID or `(row, col)` does not match `center`.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+    /// let rebuilt = HexCell::from_parts(cell.id.clone(), cell.center, 12, cell.row, cell.col)?;
+    /// assert_eq!(cell, rebuilt);
+    ///
+    /// assert!(HexCell::from_parts(cell.id, cell.center, 12, cell.row + 1, cell.col).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_parts(
+        id: String,
+        center: Point<f64>,
+        zoom_level: u8,
+        row: i64,
+        col: i64,
+    ) -> Result<Self, N3gbError> {
+        let (version, easting, northing, id_zoom) = decode_hex_identifier(&id)?;
+        if id_zoom != zoom_level {
+            return Err(N3gbError::ZoomLevelMismatch(id_zoom, zoom_level));
+        }
+
+        let same_point = |a: &Point<f64>, b: &Point<f64>, precision: IdentifierPrecision| {
+            precision.quantize(a.x()) == precision.quantize(b.x())
+                && precision.quantize(a.y()) == precision.quantize(b.y())
+        };
+
+        let id_precision = IdentifierPrecision::from_version(version).unwrap_or_default();
+        if !same_point(&Point::new(easting, northing), &center, id_precision) {
+            return Err(N3gbError::InconsistentCell(format!(
+                "id {} encodes ({}, {}) but center is ({}, {})",
+                id,
+                easting,
+                northing,
+                center.x(),
+                center.y()
+            )));
+        }
+
+        let expected = row_col_to_center(row, col, zoom_level)?;
+        if !same_point(&expected, &center, IdentifierPrecision::Millimetre) {
+            return Err(N3gbError::InconsistentCell(format!(
+                "row {} col {} has center ({}, {}) but center is ({}, {})",
+                row,
+                col,
+                expected.x(),
+                expected.y(),
+                center.x(),
+                center.y()
+            )));
+        }
+
+        Ok(Self::new(id, center, zoom_level, row, col))
+    }
+
     /// Create a HexCell from an encoded hex identifier
     ///
+    /// The cell is the one whose center is nearest the decoded point, and the
+    /// returned `center` is that cell's canonical center. For whole-metre
+    /// identifiers this undoes the rounding of the stored coordinates.
+    ///
     /// # Arguments
     /// * `id` - The Base64 URL-safe encoded hex identifier to decode.
     ///
@@ -83,7 +175,7 @@ impl HexCell {
     /// Returns [`N3gbError::InvalidIdentifierLength`], [`N3gbError::InvalidChecksum`],
     /// [`N3gbError::Base64DecodeError`], or [`N3gbError::UnsupportedVersion`] if the
     /// identifier cannot be decoded, and [`N3gbError::InvalidZoomLevel`] if the decoded
-    /// zoom level is out of range while re-indexing.
+    /// zoom level is out of range, or is 15 for a whole-metre identifier.
     ///
     /// # Example
     /// ```
@@ -97,12 +189,16 @@ impl HexCell {
     /// # }
     /// ```
     pub fn from_hex_id(id: &str) -> Result<Self, N3gbError> {
-        let (_, easting, northing, zoom_level) = decode_hex_identifier(id)?;
-        let (row, col) = point_to_row_col(&(easting, northing), zoom_level)?;
+        let (version, easting, northing, zoom_level) = decode_hex_identifier(id)?;
+        let precision = IdentifierPrecision::from_version(version).unwrap_or_default();
+        if !precision.resolves_cells(zoom_level) {
+            return Err(N3gbError::InvalidZoomLevel(zoom_level));
+        }
+        let (row, col) = center_to_row_col(easting, northing, zoom_level)?;
 
         Ok(Self {
             id: id.to_string(),
-            center: Point::new(easting, northing),
+            center: row_col_to_center(row, col, zoom_level)?,
             zoom_level,
             row,
             col,
@@ -123,65 +219,156 @@ impl HexCell {
     /// # Errors
     /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
     pub fn from_line_string_bng(line: &LineString, zoom_level: u8) -> Result<Vec<Self>, N3gbError> {
-        if zoom_level > crate::index::MAX_ZOOM_LEVEL {
-            return Err(N3gbError::InvalidZoomLevel(zoom_level));
-        }
-        let cell_radius = CELL_RADIUS[zoom_level as usize];
-        let step_size = cell_radius * 0.5;
-
-        let total_length: f64 = line
-            .0
-            .windows(2)
-            .map(|w| {
-                let dx = w[1].x - w[0].x;
-                let dy = w[1].y - w[0].y;
-                (dx * dx + dy * dy).sqrt()
-            })
-            .sum();
-
-        // We add this so that we can create the hashset with a known size
-        let estimated_cells = ((total_length / cell_radius) * 1.5) as usize + line.0.len();
-        let mut seen: HashSet<(i64, i64)> = HashSet::with_capacity(estimated_cells);
-        let mut cells: Vec<HexCell> = Vec::with_capacity(estimated_cells);
-
-        // For each segment pair
-        // [A,B], [B,C], [C,D], etc
-        for window in line.0.windows(2) {
-            let start = &window[0];
-            let end = &window[1];
-
-            let dx = end.x - start.x;
-            let dy = end.y - start.y;
-            let segment_length = (dx * dx + dy * dy).sqrt();
-            let steps = (segment_length / step_size).ceil() as usize;
-
-            // Get the points in each segment
-            // Get their HexCells
-            for i in 0..=steps {
-                let t = if steps == 0 {
-                    0.0
-                } else {
-                    i as f64 / steps as f64
-                };
-                let x = start.x + t * dx;
-                let y = start.y + t * dy;
-
-                let (row, col) = point_to_row_col(&(x, y), zoom_level)?;
-
-                // true = first time we've hit this hex along the line
-                // acts as a way to not let duplicates in
-                let is_new_cell = seen.insert((row, col));
-                if is_new_cell {
-                    let center = row_col_to_center(row, col, zoom_level)?;
-                    let id = generate_hex_identifier(center.x(), center.y(), zoom_level);
-                    cells.push(HexCell::new(id, center, zoom_level, row, col));
-                }
-            }
-        }
+        let mut cells: Vec<HexCell> = Vec::new();
+        walk_line_cells(line, zoom_level, |row, col, _| {
+            let center = row_col_to_center(row, col, zoom_level)?;
+            let id = generate_hex_identifier(center.x(), center.y(), zoom_level)?;
+            cells.push(HexCell::new(id, center, zoom_level, row, col));
+            Ok(())
+        })?;
+        Ok(cells)
+    }
+
+    /// Create HexCells from a LineString in BNG coordinates, skipping cells
+    /// already emitted through the same [`LineCellDeduper`].
+    ///
+    /// Uses the same sampling and ordering as [`HexCell::from_line_string_bng`],
+    /// but shares the set of seen cells across calls, so cells common to
+    /// several lines are only returned for the first line that reaches them.
+    ///
+    /// # Arguments
+    /// * `line` - The line in British National Grid coordinates to sample.
+    /// * `deduper` - The cells emitted so far, which also sets the zoom level.
+    ///
+    /// # Returns
+    /// A vector of the cells the line passes through that no earlier line
+    /// passed to `deduper` reached.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if the deduper's zoom level exceeds the
+    /// maximum supported zoom level.
+    pub fn from_line_string_bng_deduped(
+        line: &LineString,
+        deduper: &mut LineCellDeduper,
+    ) -> Result<Vec<Self>, N3gbError> {
+        let zoom_level = deduper.zoom_level;
+        let mut cells: Vec<HexCell> = Vec::new();
+        walk_line_cells_into(line, zoom_level, &mut deduper.seen, |row, col, _| {
+            let center = row_col_to_center(row, col, zoom_level)?;
+            let id = generate_hex_identifier(center.x(), center.y(), zoom_level)?;
+            cells.push(HexCell::new(id, center, zoom_level, row, col));
+            Ok(())
+        })?;
+        Ok(cells)
+    }
 
+    /// Create HexCells from a LineString in BNG coordinates, with the distance
+    /// along the line at which each cell is first entered.
+    ///
+    /// Uses the same sampling as [`HexCell::from_line_string_bng`] and returns
+    /// the same cells in the same order. Each distance is measured from the
+    /// line's start to the first sample point that falls in the cell, so it is
+    /// accurate to the sampling step of half a cell radius. Useful for linear
+    /// referencing (chainage).
+    ///
+    /// # Arguments
+    /// * `line` - The line in British National Grid coordinates to sample.
+    /// * `zoom_level` - The zoom level (0-15) at which to generate cells.
+    ///
+    /// # Returns
+    /// A vector of `(cell, distance)` pairs, where `distance` is in meters and
+    /// never decreases along the vector.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    /// use n3gb_rs::geo_types::LineString;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let line = LineString::from(vec![(383000.0, 398000.0), (386000.0, 398000.0)]);
+    /// let measured = HexCell::from_line_string_bng_measured(&line, 10)?;
+    /// assert_eq!(measured[0].1, 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_line_string_bng_measured(
+        line: &LineString,
+        zoom_level: u8,
+    ) -> Result<Vec<(Self, f64)>, N3gbError> {
+        let mut cells: Vec<(HexCell, f64)> = Vec::new();
+        walk_line_cells(line, zoom_level, |row, col, distance| {
+            let center = row_col_to_center(row, col, zoom_level)?;
+            let id = generate_hex_identifier(center.x(), center.y(), zoom_level)?;
+            cells.push((HexCell::new(id, center, zoom_level, row, col), distance));
+            Ok(())
+        })?;
         Ok(cells)
     }
 
+    /// Redraw a LineString in BNG coordinates through the centers of the cells it
+    /// passes through.
+    ///
+    /// Uses the same sampling as [`HexCell::from_line_string_bng`]: the result has
+    /// one vertex per distinct cell, at the cell's center, in the order the line
+    /// first enters each cell. Useful for schematic rendering.
+    ///
+    /// # Arguments
+    /// * `line` - The line in British National Grid coordinates to snap.
+    /// * `zoom_level` - The zoom level (0-15) at which to resolve cells.
+    ///
+    /// # Returns
+    /// A new `LineString` through the centers of the cells the line passes through.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    /// use n3gb_rs::geo_types::LineString;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let line = LineString::from(vec![(383000.0, 398000.0), (386000.0, 398500.0)]);
+    /// let snapped = HexCell::snap_line_to_centers_bng(&line, 10)?;
+    /// assert_eq!(snapped.0.len(), HexCell::from_line_string_bng(&line, 10)?.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snap_line_to_centers_bng(
+        line: &LineString,
+        zoom_level: u8,
+    ) -> Result<LineString<f64>, N3gbError> {
+        let mut centers: Vec<Coord<f64>> = Vec::new();
+        walk_line_cells(line, zoom_level, |row, col, _| {
+            centers.push(row_col_to_center(row, col, zoom_level)?.into());
+            Ok(())
+        })?;
+        Ok(LineString::new(centers))
+    }
+
+    /// Count the cells a LineString in BNG coordinates passes through.
+    ///
+    /// Uses the same sampling as [`HexCell::from_line_string_bng`] but skips ID
+    /// generation and cell allocation, which makes it cheap for estimating workload.
+    ///
+    /// # Arguments
+    /// * `line` - The line in British National Grid coordinates to sample.
+    /// * `zoom_level` - The zoom level (0-15) at which to count cells.
+    ///
+    /// # Returns
+    /// The number of unique cells the line passes through.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    pub fn count_line_cells_bng(line: &LineString, zoom_level: u8) -> Result<usize, N3gbError> {
+        walk_line_cells(line, zoom_level, |_, _, _| Ok(()))
+    }
+
     /// Create HexCells along a LineString in WGS84 coordinates.
     ///
     /// Converts the line to BNG and returns all unique cells that intersect it.
@@ -219,7 +406,9 @@ impl HexCell {
     /// The `HexCell` containing the given coordinate.
     ///
     /// # Errors
-    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level,
+    /// and [`N3gbError::OutOfBounds`] if the coordinate is far outside the grid extents or
+    /// the cell center has a negative easting or northing.
     ///
     /// # Example
     /// ```
@@ -238,7 +427,7 @@ impl HexCell {
     pub fn from_bng(coord: &impl Coordinate, zoom_level: u8) -> Result<Self, N3gbError> {
         let (row, col) = point_to_row_col(coord, zoom_level)?;
         let center = row_col_to_center(row, col, zoom_level)?;
-        let id = generate_hex_identifier(center.x(), center.y(), zoom_level);
+        let id = generate_hex_identifier(center.x(), center.y(), zoom_level)?;
 
         Ok(Self {
             id,
@@ -249,6 +438,110 @@ impl HexCell {
         })
     }
 
+    /// Create a HexCell from British National Grid coordinates, also returning
+    /// how far the input was from the chosen cell center.
+    ///
+    /// The offset is a useful quality metric when indexing noisy points.
+    ///
+    /// # Arguments
+    /// * `coord` - The BNG coordinate (tuple or `Point`) to index.
+    /// * `zoom_level` - The zoom level (0-15) at which to generate the cell.
+    ///
+    /// # Returns
+    /// A tuple of the `HexCell` containing the coordinate and the Euclidean
+    /// distance in meters from the coordinate to the cell center.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let (cell, offset) = HexCell::from_bng_with_offset(&(383640.0, 398260.0), 12)?;
+    /// println!("{} is {:.2}m from the input", cell.id, offset);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_bng_with_offset(
+        coord: &impl Coordinate,
+        zoom_level: u8,
+    ) -> Result<(Self, f64), N3gbError> {
+        let cell = Self::from_bng(coord, zoom_level)?;
+        let offset = (coord.x() - cell.easting()).hypot(coord.y() - cell.northing());
+        Ok((cell, offset))
+    }
+
+    /// Create a HexCell from British National Grid coordinates, also returning
+    /// how close the input is to being assigned a different cell.
+    ///
+    /// Points are assigned by rounding in the grid's row/column space, so the
+    /// region that maps to a cell is a `CELL_WIDTHS[z]` by `1.5 * CELL_RADIUS[z]`
+    /// rectangle. On even rows it is centred on the cell center; on odd rows it
+    /// runs from the center one full width east. The margin is the distance
+    /// from the input to the nearest edge of that region; a margin near zero
+    /// means a tiny change in the coordinate could flip the assigned cell.
+    ///
+    /// # Arguments
+    /// * `coord` - The BNG coordinate (tuple or `Point`) to index.
+    /// * `zoom_level` - The zoom level (0-15) at which to generate the cell.
+    ///
+    /// # Returns
+    /// A tuple of the `HexCell` containing the coordinate and the stability
+    /// margin in meters.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    pub fn from_bng_with_stability(
+        coord: &impl Coordinate,
+        zoom_level: u8,
+    ) -> Result<(Self, f64), N3gbError> {
+        let cell = Self::from_bng(coord, zoom_level)?;
+        let dx = CELL_WIDTHS[zoom_level as usize];
+        let dy = 1.5 * CELL_RADIUS[zoom_level as usize];
+
+        // Mirror `point_to_row_col`, which rounds `qx - parity` on odd rows
+        let parity = cell.row.rem_euclid(2) as f64;
+        let west = GRID_EXTENTS[0] + (cell.col as f64 + parity - 0.5) * dx;
+        let south = GRID_EXTENTS[1] + (cell.row as f64 - 0.5) * dy;
+        let margin_x = (coord.x() - west).min(west + dx - coord.x());
+        let margin_y = (coord.y() - south).min(south + dy - coord.y());
+        Ok((cell, margin_x.min(margin_y).max(0.0)))
+    }
+
+    /// Create one HexCell per requested zoom level from a single BNG coordinate.
+    ///
+    /// # Arguments
+    /// * `coord` - The BNG coordinate (tuple or `Point`) to index.
+    /// * `zoom_levels` - The zoom levels (0-15) to generate cells at.
+    ///
+    /// # Returns
+    /// The cells containing the coordinate, in the same order as `zoom_levels`.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::InvalidZoomLevel`] if any zoom level exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cells = HexCell::from_bng_multi(&(383640.0, 398260.0), &[6, 9, 12])?;
+    /// assert_eq!(cells.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_bng_multi(
+        coord: &impl Coordinate,
+        zoom_levels: &[u8],
+    ) -> Result<Vec<Self>, N3gbError> {
+        zoom_levels
+            .iter()
+            .map(|&zoom_level| Self::from_bng(coord, zoom_level))
+            .collect()
+    }
+
     /// Create a HexCell from WGS84 (lon/lat) coordinates
     ///
     /// Use this when you have a known WGS84 point. For arbitrary or parsed geometry
@@ -289,6 +582,107 @@ impl HexCell {
         Self::from_bng(&bng, zoom_level)
     }
 
+    /// Create a HexCell from WGS84 latitude and longitude given in that order.
+    ///
+    /// An unambiguous alternative to [`HexCell::from_wgs84`], which expects
+    /// `(longitude, latitude)`. The arguments are reordered before projecting.
+    ///
+    /// # Arguments
+    /// * `lat` - Latitude in decimal degrees.
+    /// * `lon` - Longitude in decimal degrees.
+    /// * `zoom_level` - The zoom level (0-15) at which to generate the cell.
+    /// * `method` - The coordinate conversion method used to project WGS84 to BNG.
+    ///
+    /// # Returns
+    /// The `HexCell` containing the given coordinate.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::ProjectionError`] if converting the coordinate to BNG fails, and
+    /// [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::{HexCell, ConversionMethod};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_lat_lon(53.481, -2.248, 12, ConversionMethod::Proj)?;
+    /// println!("Cell ID: {}", cell.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_lat_lon(
+        lat: f64,
+        lon: f64,
+        zoom_level: u8,
+        method: ConversionMethod,
+    ) -> Result<Self, N3gbError> {
+        Self::from_wgs84(&(lon, lat), zoom_level, method)
+    }
+
+    /// Create one HexCell per requested zoom level from a single WGS84 coordinate.
+    ///
+    /// The coordinate is projected to BNG once and reused for every zoom level,
+    /// which is cheaper than calling [`HexCell::from_wgs84`] per zoom.
+    ///
+    /// # Arguments
+    /// * `coord` - The WGS84 coordinate (tuple or `Point`) to index.
+    /// * `zoom_levels` - The zoom levels (0-15) to generate cells at.
+    /// * `method` - The coordinate conversion method used to project WGS84 to BNG.
+    ///
+    /// # Returns
+    /// The cells containing the coordinate, in the same order as `zoom_levels`.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::ProjectionError`] if converting the coordinate to BNG fails, and
+    /// [`N3gbError::InvalidZoomLevel`] if any zoom level exceeds the maximum supported zoom level.
+    pub fn from_wgs84_multi(
+        coord: &impl Coordinate,
+        zoom_levels: &[u8],
+        method: ConversionMethod,
+    ) -> Result<Vec<Self>, N3gbError> {
+        let bng = convert_to_bng(coord, method)?;
+        Self::from_bng_multi(&bng, zoom_levels)
+    }
+
+    /// Create a HexCell from the center of a geohash.
+    ///
+    /// Decodes the geohash to its center longitude/latitude and delegates to
+    /// [`HexCell::from_wgs84`]. Note that a coarse geohash covers a much larger
+    /// area than its center cell.
+    ///
+    /// # Arguments
+    /// * `geohash` - The geohash string to decode (up to 12 characters).
+    /// * `zoom_level` - The zoom level (0-15) at which to generate the cell.
+    /// * `method` - The coordinate conversion method used to project WGS84 to BNG.
+    ///
+    /// # Returns
+    /// The `HexCell` containing the geohash center.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::GeometryParseError`] if the geohash is invalid,
+    /// [`N3gbError::ProjectionError`] if converting its center to BNG fails, and
+    /// [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported zoom level.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::{HexCell, ConversionMethod};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_geohash("gcw2jb", 10, ConversionMethod::Proj)?;
+    /// println!("Cell ID: {}", cell.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_geohash(
+        geohash: &str,
+        zoom_level: u8,
+        method: ConversionMethod,
+    ) -> Result<Self, N3gbError> {
+        let (center, _, _) =
+            geohash::decode(geohash).map_err(|e| N3gbError::GeometryParseError(e.to_string()))?;
+        Self::from_wgs84(&(center.x, center.y), zoom_level, method)
+    }
+
     /// Create HexCells from an arbitrary `geo_types::Geometry`.
     ///
     /// This is the general-purpose dispatcher for input whose type is only known
@@ -453,33 +847,524 @@ impl HexCell {
         Ok(dist as u64)
     }
 
-    /// Returns the easting (x-coordinate) of the cell center in meters.
+    /// Returns whether `other` is one of this cell's six neighbours.
     ///
-    /// # Returns
-    /// The easting (x-coordinate) of the cell center in meters.
-    pub fn easting(&self) -> f64 {
-        self.center.x()
-    }
-
-    /// Returns the northing (y-coordinate) of the cell center in meters.
+    /// Cheaper than checking [`HexCell::grid_distance`] for a distance of 1, as it
+    /// only compares `(row, col)` against the neighbour offsets for this cell's
+    /// row parity. Cells at different zoom levels are never adjacent, and a cell
+    /// is not adjacent to itself.
+    ///
+    /// # Arguments
+    /// * `other` - The cell to test.
     ///
     /// # Returns
-    /// The northing (y-coordinate) of the cell center in meters.
-    pub fn northing(&self) -> f64 {
-        self.center.y()
-    }
-
+    /// `true` if both cells share a zoom level and `other` neighbours this cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::{HexCell, CELL_WIDTHS};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let a = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let b = HexCell::from_bng(&(a.easting() + CELL_WIDTHS[10], a.northing()), 10)?;
+    /// assert!(a.is_adjacent(&b));
+    /// assert!(!a.is_adjacent(&a));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_adjacent(&self, other: &HexCell) -> bool {
+        self.zoom_level == other.zoom_level
+            && neighbor_row_cols(self.row, self.col).contains(&(other.row, other.col))
+    }
+
+    /// Returns the compass bearing from this cell's center to another's.
+    ///
+    /// Bearings are measured clockwise from BNG grid north, which differs slightly
+    /// from true north away from the projection's central meridian.
+    ///
+    /// # Arguments
+    /// * `other` - The cell to take the bearing to.
+    ///
+    /// # Returns
+    /// The bearing in degrees, in the range `[0, 360)`. `0.0` if both centers coincide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::{HexCell, HexDirection};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let east = cell.neighbor(HexDirection::E)?;
+    /// assert!((cell.bearing_to(&east) - 90.0).abs() < 1e-9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bearing_to(&self, other: &HexCell) -> f64 {
+        let dx = other.easting() - self.easting();
+        let dy = other.northing() - self.northing();
+        dx.atan2(dy).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Returns the geodesic distance between this cell's center and another's.
+    ///
+    /// Both centers are reprojected to WGS84 with the preferred conversion method
+    /// (OSTN15 when the `ostn15` feature is enabled, PROJ otherwise) and measured
+    /// on the WGS84 ellipsoid. Comparing the result with the planar BNG distance
+    /// between the centers shows the projection's scale distortion.
+    ///
+    /// # Arguments
+    /// * `other` - The cell to measure the distance to.
+    ///
+    /// # Returns
+    /// The distance in meters along the ellipsoid.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::ProjectionError`] if either center cannot be
+    /// reprojected to WGS84.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let a = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let b = HexCell::from_bng(&(393640.0, 398260.0), 10)?;
+    /// let planar = (b.easting() - a.easting()).hypot(b.northing() - a.northing());
+    /// assert!((a.geodesic_distance_m(&b)? - planar).abs() < 0.01 * planar);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn geodesic_distance_m(&self, other: &HexCell) -> Result<f64, N3gbError> {
+        let method = preferred_method();
+        let from = convert_to_wgs84(&self.center, method)?;
+        let to = convert_to_wgs84(&other.center, method)?;
+        Ok(Geodesic.distance(from, to))
+    }
+
+    /// Returns the neighbouring cell in the given direction.
+    ///
+    /// # Arguments
+    /// * `dir` - The direction of the neighbour.
+    ///
+    /// # Returns
+    /// The adjacent `HexCell` at the same zoom level.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::OutOfBounds`] if the neighbour's center has a negative
+    /// easting or northing, i.e. it lies off the south or west edge of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::{HexCell, HexDirection};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let east = cell.neighbor(HexDirection::E)?;
+    /// assert_eq!((east.row, east.col), (cell.row, cell.col + 1));
+    /// assert_eq!(east.neighbor(HexDirection::W)?, cell);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn neighbor(&self, dir: HexDirection) -> Result<HexCell, N3gbError> {
+        let (row, col) = neighbor_row_cols(self.row, self.col)[dir as usize];
+        let center = row_col_to_center(row, col, self.zoom_level)?;
+        let id = generate_hex_identifier(center.x(), center.y(), self.zoom_level)?;
+        Ok(Self::new(id, center, self.zoom_level, row, col))
+    }
+
+    /// Returns the easting (x-coordinate) of the cell center in meters.
+    ///
+    /// # Returns
+    /// The easting (x-coordinate) of the cell center in meters.
+    pub fn easting(&self) -> f64 {
+        self.center.x()
+    }
+
+    /// Returns the northing (y-coordinate) of the cell center in meters.
+    ///
+    /// # Returns
+    /// The northing (y-coordinate) of the cell center in meters.
+    pub fn northing(&self) -> f64 {
+        self.center.y()
+    }
+
+    /// Returns the center coordinates as the scaled integers encoded in the cell ID.
+    ///
+    /// These are the same `(easting * 1000, northing * 1000)` values stored in the
+    /// identifier payload, so they can key an external index without decoding Base64.
+    ///
+    /// # Returns
+    /// A tuple of `(scaled_easting, scaled_northing)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let (x, y) = cell.scaled_coords();
+    /// assert_eq!(x, (cell.easting() * 1000.0).round() as u64);
+    /// assert_eq!(y, (cell.northing() * 1000.0).round() as u64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scaled_coords(&self) -> (u64, u64) {
+        (
+            scale_coordinate(self.easting()),
+            scale_coordinate(self.northing()),
+        )
+    }
+
+    /// Returns the Ordnance Survey 100km grid square containing the cell center.
+    ///
+    /// The square is given by its two-letter code (e.g. `SJ` for Manchester, `TQ`
+    /// for London), which makes a convenient partition key for tiled storage.
+    ///
+    /// # Returns
+    /// The two-letter 100km square code.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::OutOfBounds`] if the center lies outside the lettered
+    /// national grid (0-700km east, 0-1300km north). Cells along the northern edge
+    /// of the hex grid extent can fall outside it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// assert_eq!(cell.os_tile()?, "SJ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn os_tile(&self) -> Result<String, N3gbError> {
+        // The 5x5 lettering skips `I`, leaving 25 letters
+        const LETTERS: &[u8; 25] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
+        let (easting, northing) = (self.easting(), self.northing());
+        if !(0.0..700000.0).contains(&easting) || !(0.0..1300000.0).contains(&northing) {
+            return Err(N3gbError::OutOfBounds(format!(
+                "center ({}, {}) is outside the OS national grid squares",
+                easting, northing
+            )));
+        }
+
+        let e100k = (easting / 100000.0) as usize;
+        let n100k = (northing / 100000.0) as usize;
+
+        // The first letter picks the 500km square and the second the 100km square
+        // within it; both 5x5 blocks are lettered row by row from the top left
+        let from_top = 19 - n100k;
+        let first = from_top - from_top % 5 + (e100k + 10) / 5;
+        let second = (from_top * 5) % 25 + e100k % 5;
+
+        Ok([LETTERS[first], LETTERS[second]]
+            .iter()
+            .map(|&b| b as char)
+            .collect())
+    }
+
+    /// Returns the zoom level of this cell.
+    ///
+    /// Prefer this over reading the `zoom_level` field directly.
+    ///
+    /// # Returns
+    /// The zoom level (0-15) of this cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// assert_eq!(cell.zoom(), 10);
+    /// assert_eq!(cell.row(), cell.row);
+    /// assert_eq!(cell.col(), cell.col);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn zoom(&self) -> u8 {
+        self.zoom_level
+    }
+
+    /// Returns the row index of this cell in the hexagonal grid.
+    ///
+    /// # Returns
+    /// The row index of this cell.
+    pub fn row(&self) -> i64 {
+        self.row
+    }
+
+    /// Returns the column index of this cell in the hexagonal grid.
+    ///
+    /// # Returns
+    /// The column index of this cell.
+    pub fn col(&self) -> i64 {
+        self.col
+    }
+
+    /// Compares two cells, allowing the centers to differ by a small distance.
+    ///
+    /// The derived `PartialEq` compares center coordinates exactly, which can be
+    /// brittle when cells are built on different platforms or through different
+    /// conversion paths. This checks the IDs and zoom levels for equality and the
+    /// centers to within `tol_m` meters.
+    ///
+    /// # Arguments
+    /// * `other` - The cell to compare against.
+    /// * `tol_m` - Maximum allowed distance between the two centers, in meters.
+    ///
+    /// # Returns
+    /// `true` if both cells share an ID and zoom level and their centers are within
+    /// `tol_m` meters of each other.
+    pub fn approx_eq(&self, other: &HexCell, tol_m: f64) -> bool {
+        self.id == other.id
+            && self.zoom_level == other.zoom_level
+            && (self.easting() - other.easting()).hypot(self.northing() - other.northing()) <= tol_m
+    }
+
+    /// Returns a one-line human-readable summary of the cell for debugging.
+    ///
+    /// The summary includes the ID, zoom level, row/column, BNG center, WGS84 center
+    /// and hexagon area. The WGS84 center is shown as `n/a` if reprojection fails.
+    ///
+    /// # Returns
+    /// A string of the form
+    /// `id=... zoom=12 row=.. col=.. center=(E, N) wgs84=(lon, lat) area=..m²`.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+    /// println!("{}", cell.describe());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn describe(&self) -> String {
+        let wgs84 = match convert_to_wgs84(&self.center, preferred_method()) {
+            Ok(point) => format!("({:.6}, {:.6})", point.x(), point.y()),
+            Err(_) => "n/a".to_string(),
+        };
+        format!(
+            "id={} zoom={} row={} col={} center=({:.3}, {:.3}) wgs84={} area={:.2}m²",
+            self.id,
+            self.zoom_level,
+            self.row,
+            self.col,
+            self.easting(),
+            self.northing(),
+            wgs84,
+            self.to_polygon().unsigned_area()
+        )
+    }
+
     /// Converts this cell to a hexagonal polygon.
     ///
     /// Returns a `geo_types::Polygon` representing the hexagon boundary,
     /// suitable for spatial operations or GeoJSON export.
     ///
+    /// The hexagon is pointy-top (vertices due north and south, flat sides east
+    /// and west), matching the grid lattice. Every export — Arrow, GeoParquet,
+    /// GeoJSON and CSV geometry columns — builds on this polygon, so they all
+    /// share that orientation. There is no flat-top option, since flat-top
+    /// hexagons would not tile the pointy-top lattice.
+    ///
     /// # Returns
     /// A `geo_types::Polygon` representing the hexagon boundary of this cell.
     pub fn to_polygon(&self) -> Polygon<f64> {
         create_hexagon(&self.center, CELL_RADIUS[self.zoom_level as usize])
     }
 
+    /// Returns this cell's hexagon as a `geo_types::Geometry`.
+    ///
+    /// Equivalent to `Geometry::Polygon(cell.to_polygon())`, for feeding `geo`
+    /// algorithms and other APIs that take a [`Geometry`].
+    ///
+    /// # Returns
+    /// A `Geometry::Polygon` holding [`HexCell::to_polygon`].
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    /// use n3gb_rs::geo_types::Geometry;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// assert_eq!(cell.geometry(), Geometry::Polygon(cell.to_polygon()));
+    /// assert_eq!(cell.center_geometry(), Geometry::Point(cell.center));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn geometry(&self) -> Geometry<f64> {
+        Geometry::Polygon(self.to_polygon())
+    }
+
+    /// Returns this cell's center as a `geo_types::Geometry`.
+    ///
+    /// # Returns
+    /// A `Geometry::Point` holding the cell's BNG center.
+    pub fn center_geometry(&self) -> Geometry<f64> {
+        Geometry::Point(self.center)
+    }
+
+    /// Returns the axis-aligned bounding box of this cell's hexagon.
+    ///
+    /// Computed directly from the center and radius, without building the
+    /// polygon. The hexagon is pointy-top, so the box spans the width across
+    /// the flat sides horizontally and the radius above and below the center.
+    /// Useful when inserting cells into an R-tree or other spatial index.
+    ///
+    /// # Returns
+    /// A `geo_types::Rect` in BNG coordinates that tightly encloses
+    /// [`HexCell::to_polygon`].
+    pub fn bounding_box(&self) -> Rect<f64> {
+        let radius = CELL_RADIUS[self.zoom_level as usize];
+        // Matches the vertex placement in `create_hexagon`, whose outermost
+        // corners east and west sit at 30 degrees either side of the x axis
+        let half_width = radius * 30f64.to_radians().cos();
+        Rect::new(
+            (self.center.x() - half_width, self.center.y() - radius),
+            (self.center.x() + half_width, self.center.y() + radius),
+        )
+    }
+
+    /// Packs this cell's zoom level, row and column into a single integer key.
+    ///
+    /// Cheaper to hash and compare than the Base64 [`HexCell::id`], so it suits
+    /// internal joins. Unlike the ID it does not carry the center coordinates,
+    /// and its layout is not a stable interchange format. Reverse it with
+    /// [`unpack_key`](crate::unpack_key).
+    ///
+    /// The layout, from most to least significant bit, is 4 bits of zoom
+    /// level, 30 bits of `row + 1` and 30 bits of `col + 1`, so keys at one
+    /// zoom level sort by row, then column.
+    ///
+    /// # Returns
+    /// The packed `u64` key.
+    pub fn packed_key(&self) -> u64 {
+        pack_key(self.zoom_level, self.row, self.col)
+    }
+
+    /// Returns a stable display color for this cell, derived from its ID.
+    ///
+    /// The color is a hash of the ID, so the same cell is always drawn in the
+    /// same color and neighbouring cells usually differ. It carries no meaning
+    /// beyond telling cells apart on a map.
+    ///
+    /// # Returns
+    /// A CSS-style RGB hex string such as `"#3fa2c7"`.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+    /// let color = cell.color_hex();
+    /// assert_eq!(color.len(), 7);
+    /// assert_eq!(color, HexCell::from_hex_id(&cell.id)?.color_hex());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn color_hex(&self) -> String {
+        // FNV-1a mixes its high bits best, so take the color from the top 24
+        format!("#{:06x}", id_hash(&self.id, 0) >> 40)
+    }
+
+    /// Tests whether a geometry lies entirely within this cell's hexagon.
+    ///
+    /// Uses [`geo::Contains`] on [`HexCell::to_polygon`], so a geometry that
+    /// touches the hexagon only along its boundary is not contained. Useful for
+    /// checking whether a small feature falls in a single cell.
+    ///
+    /// # Arguments
+    /// * `geom` - The geometry to test, in BNG coordinates.
+    ///
+    /// # Returns
+    /// `true` if every part of `geom` lies inside the hexagon.
+    pub fn contains_geometry(&self, geom: &Geometry<f64>) -> bool {
+        self.to_polygon().contains(geom)
+    }
+
+    /// Converts this cell to a hexagonal polygon scaled about its center.
+    ///
+    /// `scale` is applied to the cell radius, so `0.9` draws each hexagon slightly
+    /// smaller than its cell and leaves a visual gap between neighbours. The area
+    /// scales with `scale * scale`.
+    ///
+    /// # Arguments
+    /// * `scale` - Linear scale factor applied to the cell radius (`1.0` matches [`HexCell::to_polygon`]).
+    ///
+    /// # Returns
+    /// A `geo_types::Polygon` representing the scaled hexagon boundary of this cell.
+    pub fn to_polygon_scaled(&self, scale: f64) -> Polygon<f64> {
+        create_hexagon(&self.center, CELL_RADIUS[self.zoom_level as usize] * scale)
+    }
+
+    /// Converts this cell to a hexagonal polygon with a guaranteed winding order.
+    ///
+    /// [`HexCell::to_polygon`] already produces a counter-clockwise exterior ring;
+    /// this additionally passes the result through [`geo::orient`] so callers feeding
+    /// `geo` boolean operations can rely on the orientation explicitly.
+    ///
+    /// # Returns
+    /// A `geo_types::Polygon` with a counter-clockwise exterior ring.
+    pub fn to_polygon_oriented(&self) -> Polygon<f64> {
+        self.to_polygon().orient(Direction::Default)
+    }
+
+    /// Converts this cell to its hexagon polygon encoded as little-endian WKB.
+    ///
+    /// Useful for databases that ingest Well-Known Binary directly, avoiding a
+    /// round-trip through WKT.
+    ///
+    /// # Returns
+    /// The WKB bytes of the hexagon polygon in BNG coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+    /// let wkb = cell.to_wkb();
+    /// assert_eq!(wkb[0], 1); // little-endian byte order marker
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let polygon = self.to_polygon();
+        let mut buf = Vec::with_capacity(polygon_wkb_size(&polygon));
+        write_polygon(&mut buf, &polygon, &WriteOptions::default())
+            .expect("writing a 2D polygon to a Vec cannot fail");
+        buf
+    }
+
+    /// Splits this cell's hexagon into a fan of six triangles around the center.
+    ///
+    /// Triangle `i` joins the center to corners `i` and `i + 1` of the
+    /// counter-clockwise exterior ring, so every triangle is also
+    /// counter-clockwise. Handy for GPU rendering.
+    ///
+    /// # Returns
+    /// Six triangles that together cover exactly the hexagon.
+    pub fn to_triangles(&self) -> [Triangle<f64>; 6] {
+        let polygon = self.to_polygon();
+        let corners = &polygon.exterior().0;
+        let center = self.center.0;
+        std::array::from_fn(|i| Triangle::new(center, corners[i], corners[i + 1]))
+    }
+
     /// Converts this cell's center to an Arrow PointArray.
     ///
     /// # Returns
@@ -488,38 +1373,236 @@ impl HexCell {
         std::slice::from_ref(self).to_arrow_points()
     }
 
-    /// Converts this cell to an Arrow PolygonArray.
-    ///
-    /// # Returns
-    /// A `PolygonArray` containing this cell's hexagon polygon.
-    pub fn to_arrow_polygons(&self) -> PolygonArray {
-        std::slice::from_ref(self).to_arrow_polygons()
+    /// Converts this cell to an Arrow PolygonArray.
+    ///
+    /// # Returns
+    /// A `PolygonArray` containing this cell's hexagon polygon.
+    pub fn to_arrow_polygons(&self) -> PolygonArray {
+        std::slice::from_ref(self).to_arrow_polygons()
+    }
+
+    /// Converts this cell to an Arrow RecordBatch with all attributes.
+    ///
+    /// # Returns
+    /// A `RecordBatch` containing this cell's attributes and geometry.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::IoError`] if building the record batch fails.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, N3gbError> {
+        std::slice::from_ref(self).to_record_batch()
+    }
+
+    /// Writes this cell to a GeoParquet file.
+    ///
+    /// # Arguments
+    /// * `path` - The filesystem path to write the GeoParquet file to.
+    ///
+    /// # Returns
+    /// `()` on success once the file has been written.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::IoError`] if writing the GeoParquet file fails.
+    pub fn to_geoparquet(&self, path: impl AsRef<Path>) -> Result<(), N3gbError> {
+        std::slice::from_ref(self).to_geoparquet(path)
+    }
+}
+
+/// Generates line cells across many lines, skipping cells already emitted.
+///
+/// [`HexCell::from_line_string_bng`] only removes duplicates within a single
+/// line, so adjacent or overlapping lines converted one by one emit their
+/// shared cells once per line. A `LineCellDeduper` passed to
+/// [`HexCell::from_line_string_bng_deduped`] keeps the set of cells emitted
+/// between calls, so each cell is returned only the first time any line
+/// reaches it.
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::{HexCell, LineCellDeduper};
+/// use n3gb_rs::geo_types::LineString;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let a = LineString::from(vec![(383000.0, 398000.0), (385000.0, 398000.0)]);
+/// let b = LineString::from(vec![(384000.0, 398000.0), (386000.0, 398000.0)]);
+///
+/// let mut deduper = LineCellDeduper::new(10);
+/// let first = HexCell::from_line_string_bng_deduped(&a, &mut deduper)?;
+/// let second = HexCell::from_line_string_bng_deduped(&b, &mut deduper)?;
+/// assert!(second.iter().all(|cell| !first.contains(cell)));
+/// assert_eq!(deduper.len(), first.len() + second.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineCellDeduper {
+    zoom_level: u8,
+    seen: HashSet<(i64, i64)>,
+}
+
+impl LineCellDeduper {
+    /// Creates a deduper that has not yet emitted any cells.
+    ///
+    /// # Arguments
+    /// * `zoom_level` - The zoom level (0-15) at which to generate cells.
+    ///
+    /// # Returns
+    /// An empty `LineCellDeduper`.
+    pub fn new(zoom_level: u8) -> Self {
+        Self {
+            zoom_level,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Returns the zoom level cells are generated at.
+    pub fn zoom_level(&self) -> u8 {
+        self.zoom_level
+    }
+
+    /// Returns the number of unique cells emitted so far.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns true if no cells have been emitted yet.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Forgets every emitted cell, so the next line starts afresh.
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
+
+/// Hashes a cell ID with a seeded 64-bit FNV-1a hash.
+///
+/// Used to rank cells for [`HexGrid::sample`](crate::HexGrid::sample) and to
+/// derive [`HexCell::color_hex`], so both are stable across runs and platforms.
+///
+/// # Arguments
+/// * `id` - The cell ID.
+/// * `seed` - The seed, hashed before the ID.
+///
+/// # Returns
+/// The hash of `seed` followed by the bytes of `id`.
+pub(crate) fn id_hash(id: &str, seed: u64) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    seed.to_le_bytes()
+        .iter()
+        .chain(id.as_bytes())
+        .fold(FNV_OFFSET, |hash, &b| {
+            (hash ^ b as u64).wrapping_mul(FNV_PRIME)
+        })
+}
+
+/// Sample points along a BNG line and visit each unique `(row, col)` it passes through.
+///
+/// # Arguments
+/// * `line` - The line in British National Grid coordinates to sample.
+/// * `zoom_level` - The zoom level (0-15) at which to resolve cells.
+/// * `on_new_cell` - Called with the row, column and along-line distance of the first
+///   sample that hits each cell.
+///
+/// # Returns
+/// The number of unique cells visited.
+///
+/// # Errors
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported
+/// zoom level, or any error returned by `on_new_cell`.
+fn walk_line_cells<F>(line: &LineString, zoom_level: u8, on_new_cell: F) -> Result<usize, N3gbError>
+where
+    F: FnMut(i64, i64, f64) -> Result<(), N3gbError>,
+{
+    walk_line_cells_into(line, zoom_level, &mut HashSet::new(), on_new_cell)
+}
+
+/// Sample points along a BNG line and visit each `(row, col)` it passes through
+/// that is not already in `seen`.
+///
+/// # Arguments
+/// * `line` - The line in British National Grid coordinates to sample.
+/// * `zoom_level` - The zoom level (0-15) at which to resolve cells.
+/// * `seen` - The cells already visited; each newly visited cell is added to it.
+/// * `on_new_cell` - Called with the row, column and along-line distance of the first
+///   sample that hits each new cell.
+///
+/// # Returns
+/// The number of new cells visited.
+///
+/// # Errors
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum supported
+/// zoom level, or any error returned by `on_new_cell`.
+fn walk_line_cells_into<F>(
+    line: &LineString,
+    zoom_level: u8,
+    seen: &mut HashSet<(i64, i64)>,
+    mut on_new_cell: F,
+) -> Result<usize, N3gbError>
+where
+    F: FnMut(i64, i64, f64) -> Result<(), N3gbError>,
+{
+    if zoom_level > crate::index::MAX_ZOOM_LEVEL {
+        return Err(N3gbError::InvalidZoomLevel(zoom_level));
     }
-
-    /// Converts this cell to an Arrow RecordBatch with all attributes.
-    ///
-    /// # Returns
-    /// A `RecordBatch` containing this cell's attributes and geometry.
-    ///
-    /// # Errors
-    /// Returns [`N3gbError::IoError`] if building the record batch fails.
-    pub fn to_record_batch(&self) -> Result<RecordBatch, N3gbError> {
-        std::slice::from_ref(self).to_record_batch()
+    let cell_radius = CELL_RADIUS[zoom_level as usize];
+    let step_size = cell_radius * 0.5;
+
+    let total_length: f64 = line
+        .0
+        .windows(2)
+        .map(|w| {
+            let dx = w[1].x - w[0].x;
+            let dy = w[1].y - w[0].y;
+            (dx * dx + dy * dy).sqrt()
+        })
+        .sum();
+
+    // We add this so that we can create the hashset with a known size
+    let estimated_cells = ((total_length / cell_radius) * 1.5) as usize + line.0.len();
+    seen.reserve(estimated_cells);
+    let mut new_cells = 0;
+    let mut travelled = 0.0;
+
+    // For each segment pair
+    // [A,B], [B,C], [C,D], etc
+    for window in line.0.windows(2) {
+        let start = &window[0];
+        let end = &window[1];
+
+        let dx = end.x - start.x;
+        let dy = end.y - start.y;
+        let segment_length = (dx * dx + dy * dy).sqrt();
+        let steps = (segment_length / step_size).ceil() as usize;
+
+        // Get the points in each segment
+        // Get their HexCells
+        for i in 0..=steps {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                i as f64 / steps as f64
+            };
+            let x = start.x + t * dx;
+            let y = start.y + t * dy;
+
+            let (row, col) = point_to_row_col(&(x, y), zoom_level)?;
+
+            // true = first time we've hit this hex along the line
+            // acts as a way to not let duplicates in
+            let is_new_cell = seen.insert((row, col));
+            if is_new_cell {
+                new_cells += 1;
+                on_new_cell(row, col, travelled + t * segment_length)?;
+            }
+        }
+        travelled += segment_length;
     }
 
-    /// Writes this cell to a GeoParquet file.
-    ///
-    /// # Arguments
-    /// * `path` - The filesystem path to write the GeoParquet file to.
-    ///
-    /// # Returns
-    /// `()` on success once the file has been written.
-    ///
-    /// # Errors
-    /// Returns [`N3gbError::IoError`] if writing the GeoParquet file fails.
-    pub fn to_geoparquet(&self, path: impl AsRef<Path>) -> Result<(), N3gbError> {
-        std::slice::from_ref(self).to_geoparquet(path)
-    }
+    Ok(new_cells)
 }
 
 #[cfg(test)]
@@ -552,6 +1635,110 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_is_adjacent() -> Result<(), N3gbError> {
+        let grid =
+            crate::HexGrid::from_bng_extent(&(457000.0, 339500.0), &(457600.0, 340100.0), 10)?;
+        let center = grid.get_cell_at(&Point::new(457300.0, 339800.0)).unwrap();
+
+        for cell in grid.iter() {
+            assert_eq!(center.is_adjacent(cell), center.grid_distance(cell)? == 1);
+            assert_eq!(center.is_adjacent(cell), cell.is_adjacent(center));
+        }
+        assert_eq!(grid.iter().filter(|c| center.is_adjacent(c)).count(), 6);
+        assert!(!center.is_adjacent(center));
+
+        let distant = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        assert!(!center.is_adjacent(&distant));
+
+        let coarser = HexCell::from_bng(&center.center, 9)?;
+        assert!(!center.is_adjacent(&coarser));
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbor_directions() -> Result<(), N3gbError> {
+        let start = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+
+        let mut cell = start.clone();
+        for step in 1..=6 {
+            let next = cell.neighbor(HexDirection::E)?;
+            assert_eq!(next.row, start.row);
+            assert_eq!(next.col, start.col + step);
+            assert!(next.easting() > cell.easting());
+            cell = next;
+        }
+
+        let opposites = [
+            (HexDirection::E, HexDirection::W),
+            (HexDirection::NE, HexDirection::SW),
+            (HexDirection::NW, HexDirection::SE),
+        ];
+        // Check from both row parities.
+        for origin in [start.clone(), start.neighbor(HexDirection::NE)?] {
+            for (dir, back) in opposites {
+                let there = origin.neighbor(dir)?;
+                assert!(origin.is_adjacent(&there));
+                assert_eq!(there.neighbor(back)?, origin);
+            }
+            assert!(origin.neighbor(HexDirection::NE)?.northing() > origin.northing());
+            assert!(origin.neighbor(HexDirection::SW)?.easting() < origin.easting());
+        }
+
+        let corner = HexCell::from_bng(&(0.0, 0.0), 10)?;
+        assert!(matches!(
+            corner.neighbor(HexDirection::W),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bearing_to() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        let dy = 1.5 * CELL_RADIUS[10];
+
+        let east = cell.neighbor(HexDirection::E)?;
+        let north = HexCell::from_bng(&(cell.easting(), cell.northing() + 2.0 * dy), 10)?;
+        let south = HexCell::from_bng(&(cell.easting(), cell.northing() - 2.0 * dy), 10)?;
+        let west = cell.neighbor(HexDirection::W)?;
+
+        assert!((cell.bearing_to(&east) - 90.0).abs() < 1e-9);
+        assert!(cell.bearing_to(&north).abs() < 1e-9);
+        assert!((cell.bearing_to(&south) - 180.0).abs() < 1e-9);
+        assert!((cell.bearing_to(&west) - 270.0).abs() < 1e-9);
+        assert_eq!(cell.bearing_to(&cell), 0.0);
+
+        let ne = cell.bearing_to(&cell.neighbor(HexDirection::NE)?);
+        assert!((ne - 30.0).abs() < 0.1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_geodesic_distance_m() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        assert_eq!(cell.geodesic_distance_m(&cell)?, 0.0);
+
+        for offset in [
+            (0.0, 0.0),
+            (917.0, 0.0),
+            (5000.0, -3000.0),
+            (-20000.0, 15000.0),
+        ] {
+            let other =
+                HexCell::from_bng(&(cell.easting() + offset.0, cell.northing() + offset.1), 10)?;
+            let other = other.neighbor(HexDirection::NE)?;
+            let planar =
+                (other.easting() - cell.easting()).hypot(other.northing() - cell.northing());
+            let geodesic = cell.geodesic_distance_m(&other)?;
+
+            // BNG scale factor stays within about 0.1% of unity over England
+            assert!((geodesic - planar).abs() < 0.002 * planar);
+            assert!((geodesic - other.geodesic_distance_m(&cell)?).abs() < 1e-6);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_from_bng_tuple() -> Result<(), N3gbError> {
         let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
@@ -615,6 +1802,595 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_equal_address_gives_bitwise_equal_center() -> Result<(), N3gbError> {
+        // Deterministic LCG so the sweep is reproducible without a rand dependency
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        for _ in 0..2000 {
+            // Keep clear of the grid edge, where a cell center can fall below zero
+            let easting = 50000.0 + next() * 600000.0;
+            let northing = 50000.0 + next() * 1150000.0;
+            let zoom_level = 5 + (next() * 11.0) as u8;
+
+            let cell = HexCell::from_bng(&(easting, northing), zoom_level)?;
+            let from_center = HexCell::from_bng(&cell.center, zoom_level)?;
+            let from_id = HexCell::from_hex_id(&cell.id)?;
+
+            for other in [&from_center, &from_id] {
+                assert_eq!((other.row, other.col), (cell.row, cell.col));
+                assert_eq!(other.center.x().to_bits(), cell.center.x().to_bits());
+                assert_eq!(other.center.y().to_bits(), cell.center.y().to_bits());
+                assert_eq!(*other, cell);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bng_with_offset() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+
+        let (at_center, offset) = HexCell::from_bng_with_offset(&cell.center, 10)?;
+        assert_eq!(at_center.id, cell.id);
+        assert!(offset < 1e-9);
+
+        // Just inside the east edge of the hexagon
+        let half_width = crate::index::CELL_WIDTHS[10] / 2.0;
+        let edge = (cell.easting() + half_width - 0.01, cell.northing());
+        let (at_edge, edge_offset) = HexCell::from_bng_with_offset(&edge, 10)?;
+        assert_eq!(at_edge.id, cell.id);
+        assert!(edge_offset > offset);
+        assert!(edge_offset <= CELL_RADIUS[10]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_line_string_bng_measured() -> Result<(), N3gbError> {
+        let line = LineString::from(vec![
+            (383000.0, 398000.0),
+            (386000.0, 398000.0),
+            (386000.0, 401000.0),
+            (384000.0, 399500.0),
+        ]);
+        let measured = HexCell::from_line_string_bng_measured(&line, 10)?;
+        let cells = HexCell::from_line_string_bng(&line, 10)?;
+
+        assert_eq!(measured.len(), cells.len());
+        assert!(measured.iter().zip(&cells).all(|((m, _), c)| m == c));
+        assert_eq!(measured[0].1, 0.0);
+        assert!(measured.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let length = 3000.0 + 3000.0 + 2500.0;
+        assert!(measured.last().unwrap().1 <= length + 1e-9);
+
+        // On the first straight leg, the entry distance matches the offset along it
+        for (cell, distance) in measured.iter().filter(|(_, d)| *d < 3000.0) {
+            let sample = (383000.0 + distance, 398000.0);
+            assert_eq!(*cell, HexCell::from_bng(&sample, 10)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_cell_deduper_across_lines() -> Result<(), N3gbError> {
+        let a = LineString::from(vec![(383000.0, 398000.0), (385000.0, 398500.0)]);
+        let b = LineString::from(vec![(384000.0, 398250.0), (386000.0, 399000.0)]);
+
+        let independent = HexCell::from_line_string_bng(&a, 12)?.len()
+            + HexCell::from_line_string_bng(&b, 12)?.len();
+
+        let mut deduper = LineCellDeduper::new(12);
+        let first = HexCell::from_line_string_bng_deduped(&a, &mut deduper)?;
+        let second = HexCell::from_line_string_bng_deduped(&b, &mut deduper)?;
+        assert!(first.len() + second.len() < independent);
+        assert_eq!(deduper.len(), first.len() + second.len());
+
+        // The first line matches the plain conversion, and no cell repeats
+        assert_eq!(first, HexCell::from_line_string_bng(&a, 12)?);
+        let ids: HashSet<&str> = first.iter().chain(&second).map(|c| c.id.as_str()).collect();
+        assert_eq!(ids.len(), deduper.len());
+
+        deduper.clear();
+        assert!(deduper.is_empty());
+        assert_eq!(HexCell::from_line_string_bng_deduped(&b, &mut deduper)?, {
+            HexCell::from_line_string_bng(&b, 12)?
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_line_cells_bng() -> Result<(), N3gbError> {
+        let line = LineString::from(vec![
+            (383000.0, 398000.0),
+            (384000.0, 398500.0),
+            (384500.0, 399500.0),
+        ]);
+        let count = HexCell::count_line_cells_bng(&line, 12)?;
+        assert_eq!(count, HexCell::from_line_string_bng(&line, 12)?.len());
+        assert!(count > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snap_line_to_centers_bng() -> Result<(), N3gbError> {
+        // Doubles back over its own cells
+        let line = LineString::from(vec![
+            (383000.0, 398000.0),
+            (384000.0, 398500.0),
+            (384500.0, 399500.0),
+            (383900.0, 398450.0),
+        ]);
+        let snapped = HexCell::snap_line_to_centers_bng(&line, 11)?;
+        let cells = HexCell::from_line_string_bng(&line, 11)?;
+
+        assert_eq!(snapped.0.len(), cells.len());
+        for (vertex, cell) in snapped.coords().zip(&cells) {
+            assert_eq!(Point::from(*vertex), cell.center);
+        }
+        let distinct: HashSet<(u64, u64)> = snapped
+            .coords()
+            .map(|c| (c.x.to_bits(), c.y.to_bits()))
+            .collect();
+        assert_eq!(distinct.len(), snapped.0.len());
+
+        assert!(matches!(
+            HexCell::snap_line_to_centers_bng(&line, 16),
+            Err(N3gbError::InvalidZoomLevel(16))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_polygon_scaled() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        let full_area = cell.to_polygon().unsigned_area();
+
+        assert!((cell.to_polygon_scaled(1.0).unsigned_area() - full_area).abs() < 1e-6);
+        assert!((cell.to_polygon_scaled(0.5).unsigned_area() - full_area * 0.25).abs() < 1e-6);
+
+        let half_area = cell.to_polygon_scaled(0.5f64.sqrt()).unsigned_area();
+        assert!((half_area - full_area * 0.5).abs() < 1e-6);
+
+        let scaled_centroid = cell.to_polygon_scaled(0.5).centroid().unwrap();
+        assert!((scaled_centroid.x() - cell.easting()).abs() < 1e-6);
+        assert!((scaled_centroid.y() - cell.northing()).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounding_box_contains_corners() -> Result<(), N3gbError> {
+        use geo::BoundingRect;
+
+        for zoom in [0, 6, 10, 15] {
+            let cell = HexCell::from_bng(&(383640.0, 398260.0), zoom)?;
+            let bbox = cell.bounding_box();
+            let polygon = cell.to_polygon();
+
+            for corner in polygon.exterior().coords() {
+                assert!(corner.x >= bbox.min().x && corner.x <= bbox.max().x);
+                assert!(corner.y >= bbox.min().y && corner.y <= bbox.max().y);
+            }
+
+            let expected = polygon.bounding_rect().unwrap();
+            assert!((bbox.min().x - expected.min().x).abs() < 1e-6);
+            assert!((bbox.min().y - expected.min().y).abs() < 1e-6);
+            assert!((bbox.max().x - expected.max().x).abs() < 1e-6);
+            assert!((bbox.max().y - expected.max().y).abs() < 1e-6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains_geometry() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        let (x, y) = (cell.easting(), cell.northing());
+        let radius = CELL_RADIUS[10];
+
+        let inside_point = Geometry::Point(Point::new(x + 1.0, y - 1.0));
+        let inside_line = Geometry::LineString(LineString::from(vec![
+            (x - radius * 0.4, y),
+            (x + radius * 0.4, y + radius * 0.2),
+        ]));
+        assert!(cell.contains_geometry(&inside_point));
+        assert!(cell.contains_geometry(&inside_line));
+
+        let outside_point = Geometry::Point(Point::new(x + radius * 2.0, y));
+        let crossing_line =
+            Geometry::LineString(LineString::from(vec![(x, y), (x + radius * 2.0, y)]));
+        assert!(!cell.contains_geometry(&outside_point));
+        assert!(!cell.contains_geometry(&crossing_line));
+        Ok(())
+    }
+
+    #[test]
+    fn test_color_hex() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        let color = cell.color_hex();
+        assert_eq!(color, cell.clone().color_hex());
+        assert_eq!(color, HexCell::from_hex_id(&cell.id)?.color_hex());
+        assert!(color.starts_with('#'));
+        assert_eq!(color.len(), 7);
+        assert!(color[1..].chars().all(|c| c.is_ascii_hexdigit()));
+
+        let width = crate::index::CELL_WIDTHS[10];
+        let mut ids = HashSet::new();
+        let mut colors = HashSet::new();
+        for i in 0..50 {
+            let other = HexCell::from_bng(&(383640.0 + i as f64 * width, 398260.0), 10)?;
+            ids.insert(other.id.clone());
+            colors.insert(other.color_hex());
+        }
+        assert_eq!(ids.len(), 50);
+        assert!(colors.len() >= 48, "only {} distinct colors", colors.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_key_round_trip() -> Result<(), N3gbError> {
+        use crate::index::unpack_key;
+
+        let cells = [
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(0.0, 0.0), 0)?,
+            HexCell::from_bng(&(0.0, 0.0), 15)?,
+            HexCell::from_bng(&(699999.0, 1299999.0), 15)?,
+            HexCell::from_bng(&(457500.0, 340000.0), 7)?,
+        ];
+        let mut keys = HashSet::new();
+        for cell in &cells {
+            let key = cell.packed_key();
+            assert_eq!(unpack_key(key), (cell.zoom_level, cell.row, cell.col));
+            assert!(keys.insert(key));
+        }
+
+        let left = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let right = left.neighbor(HexDirection::E)?;
+        assert!(left.packed_key() < right.packed_key());
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_is_pointy_top() -> Result<(), N3gbError> {
+        for zoom_level in [6, 10, 15] {
+            let cell = HexCell::from_bng(&(383640.0, 398260.0), zoom_level)?;
+            let polygon = cell.to_polygon();
+
+            // Vertices sit at 30°, 90°, ..., 330° from the center, so 90° is due north
+            for (i, v) in polygon.exterior().0[..6].iter().enumerate() {
+                let angle = (v.y - cell.northing())
+                    .atan2(v.x - cell.easting())
+                    .to_degrees()
+                    .rem_euclid(360.0);
+                assert!((angle - (30.0 + 60.0 * i as f64)).abs() < 1e-6);
+            }
+
+            // The flat east side is shared exactly with the east neighbour's west side
+            let east = cell.neighbor(HexDirection::E)?.to_polygon();
+            let ring = &polygon.exterior().0;
+            let east_ring = &east.exterior().0;
+            for (a, b) in [(ring[0], east_ring[2]), (ring[5], east_ring[3])] {
+                assert!((a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_winding_is_counter_clockwise() -> Result<(), N3gbError> {
+        let points = [
+            (383640.0, 398260.0),
+            (457500.0, 340000.0),
+            (530000.0, 180000.0),
+            (252086.0, 847702.0),
+        ];
+        for zoom_level in [0, 6, 10, 15] {
+            for point in &points {
+                let cell = HexCell::from_bng(point, zoom_level)?;
+                assert!(cell.to_polygon().signed_area() > 0.0);
+                assert!(cell.to_polygon_oriented().signed_area() > 0.0);
+                assert_eq!(cell.to_polygon_oriented(), cell.to_polygon());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_lat_lon_matches_from_wgs84() -> Result<(), N3gbError> {
+        let from_lat_lon = HexCell::from_lat_lon(53.481, -2.248, 12, ConversionMethod::Proj)?;
+        let from_wgs84 = HexCell::from_wgs84(&(-2.248, 53.481), 12, ConversionMethod::Proj)?;
+        assert_eq!(from_lat_lon, from_wgs84);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_geohash() -> Result<(), N3gbError> {
+        let hash = geohash::encode(geo_types::coord! { x: -2.248, y: 53.481 }, 9)
+            .map_err(|e| N3gbError::GeometryParseError(e.to_string()))?;
+        let cell = HexCell::from_geohash(&hash, 12, ConversionMethod::Proj)?;
+
+        let (center, _, _) =
+            geohash::decode(&hash).map_err(|e| N3gbError::GeometryParseError(e.to_string()))?;
+        let expected = HexCell::from_wgs84(&(center.x, center.y), 12, ConversionMethod::Proj)?;
+        assert_eq!(cell, expected);
+
+        let expected_area = crate::zoom_table()[12].area_m2;
+        assert!((cell.to_polygon().unsigned_area() - expected_area).abs() < 1e-6);
+
+        assert!(matches!(
+            HexCell::from_geohash("not-a-geohash!", 12, ConversionMethod::Proj),
+            Err(N3gbError::GeometryParseError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bng_with_stability() -> Result<(), N3gbError> {
+        let zoom_level = 10;
+        let width = CELL_WIDTHS[zoom_level as usize];
+        let half_height = 0.75 * CELL_RADIUS[zoom_level as usize];
+        let centred = (width / 2.0).min(half_height);
+
+        // Row 3538 is even, so its cell owns a box centred on its center
+        let even = HexCell::from_bng(&(383640.0, 398372.0), zoom_level)?;
+        assert_eq!(even.row, 3538);
+        let (at_center, center_margin) =
+            HexCell::from_bng_with_stability(&even.center, zoom_level)?;
+        assert_eq!(at_center, even);
+        assert!((center_margin - centred).abs() < 1e-3);
+
+        let near_east = (even.easting() + width / 2.0 - 1e-6, even.northing());
+        let (edge_cell, edge_margin) = HexCell::from_bng_with_stability(&near_east, zoom_level)?;
+        assert_eq!(edge_cell, even);
+        assert!(edge_margin < 1e-3);
+
+        // Row 3537 is odd, so its cell owns one full width east of its center
+        let odd = HexCell::from_bng(&(383640.0, 398260.0), zoom_level)?;
+        assert_eq!((odd.row, odd.col), (3537, 2950));
+        assert_eq!(odd.easting(), 383565.0);
+
+        let (at_center, center_margin) = HexCell::from_bng_with_stability(&odd.center, zoom_level)?;
+        assert_eq!(at_center, odd);
+        assert!(center_margin < 1e-3);
+        let west = HexCell::from_bng(&(odd.easting() - 0.01, odd.northing()), zoom_level)?;
+        assert_eq!(west.col, 2949);
+
+        let inside = (odd.easting() + 100.0, odd.northing());
+        let (cell, margin) = HexCell::from_bng_with_stability(&inside, zoom_level)?;
+        assert_eq!(cell, odd);
+        assert!((margin - (width - 100.0)).abs() < 1e-3);
+
+        let (cell, margin) = HexCell::from_bng_with_stability(
+            &(odd.easting() + width / 2.0, odd.northing()),
+            zoom_level,
+        )?;
+        assert_eq!(cell, odd);
+        assert!((margin - centred).abs() < 1e-3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_multi_zoom() -> Result<(), N3gbError> {
+        let zoom_levels = [3, 8, 12, 15];
+
+        let cells = HexCell::from_bng_multi(&(383640.0, 398260.0), &zoom_levels)?;
+        assert_eq!(cells.len(), zoom_levels.len());
+        for (cell, &zoom_level) in cells.iter().zip(&zoom_levels) {
+            assert_eq!(cell.zoom_level, zoom_level);
+            assert_eq!(cell, &HexCell::from_bng(&(383640.0, 398260.0), zoom_level)?);
+        }
+
+        let cells =
+            HexCell::from_wgs84_multi(&(-2.248, 53.481), &zoom_levels, ConversionMethod::Proj)?;
+        for (cell, &zoom_level) in cells.iter().zip(&zoom_levels) {
+            assert_eq!(cell.zoom_level, zoom_level);
+        }
+
+        assert!(matches!(
+            HexCell::from_bng_multi(&(383640.0, 398260.0), &[10, 16]),
+            Err(N3gbError::InvalidZoomLevel(16))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_coords_match_identifier() -> Result<(), N3gbError> {
+        for zoom_level in [0, 6, 12, 15] {
+            let cell = HexCell::from_bng(&(383640.0, 398260.0), zoom_level)?;
+            let (_, easting, northing, _) = decode_hex_identifier(&cell.id)?;
+
+            assert_eq!(
+                cell.scaled_coords(),
+                (scale_coordinate(easting), scale_coordinate(northing))
+            );
+            assert_eq!(cell.scaled_coords().0 as f64 / 1000.0, easting);
+            assert_eq!(cell.scaled_coords().1 as f64 / 1000.0, northing);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_parts() -> Result<(), N3gbError> {
+        let grid =
+            crate::HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        for cell in grid.iter() {
+            let rebuilt =
+                HexCell::from_parts(cell.id.clone(), cell.center, 10, cell.row, cell.col)?;
+            assert_eq!(&rebuilt, cell);
+        }
+
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let parts = |id: &str, center: Point<f64>, zoom, row, col| {
+            HexCell::from_parts(id.to_string(), center, zoom, row, col)
+        };
+
+        assert!(matches!(
+            parts(&cell.id, cell.center, 11, cell.row, cell.col),
+            Err(N3gbError::ZoomLevelMismatch(12, 11))
+        ));
+        let shifted = Point::new(cell.center.x() + 1.0, cell.center.y());
+        assert!(matches!(
+            parts(&cell.id, shifted, 12, cell.row, cell.col),
+            Err(N3gbError::InconsistentCell(_))
+        ));
+        assert!(matches!(
+            parts(&cell.id, cell.center, 12, cell.row, cell.col + 1),
+            Err(N3gbError::InconsistentCell(_))
+        ));
+        assert!(parts("not-an-id", cell.center, 12, cell.row, cell.col).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_metre_identifier_round_trip() -> Result<(), N3gbError> {
+        use crate::index::generate_hex_identifier_with_precision;
+        use base64::Engine;
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+        let metre_id = |center: Point<f64>, zoom| {
+            generate_hex_identifier_with_precision(
+                center.x(),
+                center.y(),
+                zoom,
+                IdentifierPrecision::Metre,
+            )
+        };
+
+        for zoom in 0..=14 {
+            let mut addresses = vec![(0, 0), (1, 0), (4, 0), (5, 3)];
+            for point in [(383640.0, 398260.0), (457500.3, 340000.7)] {
+                let (row, col) = point_to_row_col(&point, zoom)?;
+                addresses.extend([
+                    (row, col),
+                    (row + 1, col),
+                    (row, col + 1),
+                    (row + 1, col + 1),
+                ]);
+            }
+
+            for (row, col) in addresses {
+                let center = row_col_to_center(row, col, zoom)?;
+                if center.x() < 0.0 {
+                    continue;
+                }
+                let id = metre_id(center, zoom)?;
+
+                let restored = HexCell::from_hex_id(&id)?;
+                assert_eq!(
+                    (restored.row, restored.col, restored.center),
+                    (row, col, center),
+                    "zoom {} id {}",
+                    zoom,
+                    id
+                );
+
+                let rebuilt = HexCell::from_parts(id.clone(), center, zoom, row, col)?;
+                assert_eq!(rebuilt, restored);
+            }
+        }
+
+        // Whole metres cannot tell zoom 15 cells apart
+        let center = row_col_to_center(4, 0, 15)?;
+        assert_eq!(metre_id(center, 15), Err(N3gbError::InvalidZoomLevel(15)));
+        let mut bytes = vec![2u8, 0, 0, 0, 0, 0, 0, 0, 3, 15];
+        bytes.push(bytes.iter().fold(0u8, |acc, &b| acc.wrapping_add(b)));
+        assert_eq!(
+            HexCell::from_hex_id(&URL_SAFE_NO_PAD.encode(bytes)),
+            Err(N3gbError::InvalidZoomLevel(15))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bng_negative_easting() {
+        assert!(matches!(
+            HexCell::from_bng(&(-5000.0, 398260.0), 12),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn test_os_tile() -> Result<(), N3gbError> {
+        let manchester = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        assert_eq!(manchester.os_tile()?, "SJ");
+
+        let london = HexCell::from_bng(&(530500.0, 180500.0), 10)?;
+        assert_eq!(london.os_tile()?, "TQ");
+
+        let edinburgh = HexCell::from_bng(&(325500.0, 673500.0), 12)?;
+        assert_eq!(edinburgh.os_tile()?, "NT");
+
+        let shetland = HexCell::from_bng(&(445500.0, 1155500.0), 12)?;
+        assert_eq!(shetland.os_tile()?, "HU");
+
+        let north_edge = HexCell::from_bng(&(100000.0, 1340000.0), 10)?;
+        assert!(matches!(
+            north_edge.os_tile(),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let summary = cell.describe();
+
+        assert!(summary.contains(&format!("id={}", cell.id)));
+        assert!(summary.contains("zoom=12"));
+        assert!(summary.contains(&format!("row={} col={}", cell.row, cell.col)));
+        assert!(summary.contains("wgs84=(-2."));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_wkb_round_trip() -> Result<(), N3gbError> {
+        use geo_traits::to_geo::ToGeoGeometry;
+
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let wkb = cell.to_wkb();
+
+        let parsed = wkb::reader::read_wkb(&wkb)
+            .map_err(|e| N3gbError::GeometryParseError(e.to_string()))?
+            .to_geometry();
+        assert_eq!(parsed, Geometry::Polygon(cell.to_polygon()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_triangles() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 9)?;
+        let triangles = cell.to_triangles();
+
+        let hex_area = cell.to_polygon().unsigned_area();
+        let fan_area: f64 = triangles.iter().map(|t| t.signed_area()).sum();
+        assert!((fan_area - hex_area).abs() < 1e-6 * hex_area);
+        assert!(triangles.iter().all(|t| t.v1() == cell.center.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_approx_eq() -> Result<(), N3gbError> {
+        let a = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let b = HexCell::from_bng(&(383640.0 + 1e-9, 398260.0 - 1e-9), 12)?;
+        assert!(a.approx_eq(&b, 1e-6));
+
+        let mut shifted = a.clone();
+        shifted.center = Point::new(a.easting() + 1.0, a.northing());
+        assert!(!a.approx_eq(&shifted, 1e-6));
+        assert!(a.approx_eq(&shifted, 1.0));
+
+        let other_zoom = HexCell::from_bng(&(383640.0, 398260.0), 11)?;
+        assert!(!a.approx_eq(&other_zoom, 1e3));
+        Ok(())
+    }
+
     #[test]
     fn test_tuple_and_point_same_result() -> Result<(), N3gbError> {
         let from_tuple = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
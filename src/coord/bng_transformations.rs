@@ -1,9 +1,10 @@
 use crate::coord::ConversionMethod;
 use crate::error::N3gbError;
+use crate::parallel::*;
 use geo_types::{Coord, LineString, MultiPolygon, Point, Polygon};
 use proj::Proj;
-use rayon::prelude::*;
 use std::cell::RefCell;
+use std::sync::OnceLock;
 
 /// Select conversion backend at runtime based on [`ConversionMethod`].
 ///
@@ -129,9 +130,186 @@ pub(crate) fn convert_multipolygon_to_bng(
     }
 }
 
+/// Reproject a point from British National Grid to WGS84.
+///
+/// # Arguments
+/// * `coord` - The British National Grid (easting, northing) coordinate to convert.
+/// * `method` - Which conversion backend to use.
+///
+/// # Returns
+/// The coordinate reprojected to WGS84 (longitude, latitude) as a [`Point<f64>`].
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if the underlying PROJ or OSTN15
+/// conversion fails.
+pub(crate) fn convert_to_wgs84<C: super::Coordinate>(
+    coord: &C,
+    method: ConversionMethod,
+) -> Result<Point<f64>, N3gbError> {
+    let line = LineString::from(vec![(coord.x(), coord.y())]);
+    let converted = match method {
+        ConversionMethod::Proj => bng_line_to_wgs84(&line)?,
+        ConversionMethod::Ostn15 => {
+            #[cfg(feature = "ostn15")]
+            {
+                bng_line_to_wgs84_ostn15(&line)?
+            }
+            #[cfg(not(feature = "ostn15"))]
+            {
+                return Err(ostn15_disabled());
+            }
+        }
+    };
+    Ok(Point::from(converted.0[0]))
+}
+
+/// Reproject a [`Polygon`] from British National Grid to WGS84.
+///
+/// # Arguments
+/// * `polygon` - The British National Grid polygon to convert.
+/// * `method` - Which conversion backend to use.
+///
+/// # Returns
+/// The polygon reprojected to WGS84 (longitude, latitude).
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if the underlying PROJ or OSTN15
+/// conversion fails for any vertex.
+pub(crate) fn convert_polygon_to_wgs84(
+    polygon: &Polygon<f64>,
+    method: ConversionMethod,
+) -> Result<Polygon<f64>, N3gbError> {
+    let convert_line = |line: &LineString| match method {
+        ConversionMethod::Proj => bng_line_to_wgs84(line),
+        ConversionMethod::Ostn15 => {
+            #[cfg(feature = "ostn15")]
+            {
+                bng_line_to_wgs84_ostn15(line)
+            }
+            #[cfg(not(feature = "ostn15"))]
+            {
+                Err(ostn15_disabled())
+            }
+        }
+    };
+    let exterior = convert_line(polygon.exterior())?;
+    let interiors: Result<Vec<LineString>, N3gbError> =
+        polygon.interiors().iter().map(convert_line).collect();
+    Ok(Polygon::new(exterior, interiors?))
+}
+
 // Hacky work around for now!
 thread_local! {
     static WGS84_TO_BNG_PROJ_OBJECT: RefCell<Option<Proj>> = const { RefCell::new(None) };
+    static BNG_TO_WGS84_PROJ_OBJECT: RefCell<Option<Proj>> = const { RefCell::new(None) };
+}
+
+/// Run a closure with the thread-local BNG-to-WGS84 PROJ object, creating it on
+/// first use.
+///
+/// # Arguments
+/// * `proj_closure` - Closure invoked with a reference to the cached [`Proj`] object.
+///
+/// # Returns
+/// The value returned by `proj_closure`.
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if the [`Proj`] object cannot be
+/// constructed, or propagates any [`N3gbError`] returned by `proj_closure`.
+fn with_bng_to_wgs84_proj<T, F>(proj_closure: F) -> Result<T, N3gbError>
+where
+    F: FnOnce(&Proj) -> Result<T, N3gbError>,
+{
+    BNG_TO_WGS84_PROJ_OBJECT.with(|cell| {
+        let mut borrow = cell.borrow_mut();
+        if borrow.is_none() {
+            *borrow = Some(
+                Proj::new_known_crs("EPSG:27700", "EPSG:4326", None)
+                    .map_err(|e| N3gbError::ProjectionError(e.to_string()))?,
+            );
+        }
+        proj_closure(borrow.as_ref().unwrap())
+    })
+}
+
+/// Creates the PROJ objects for the current thread ahead of first use.
+///
+/// PROJ conversions cache one [`Proj`] object per direction per thread, built
+/// lazily on the first conversion. Building it reads the PROJ database, so the
+/// first conversion on each thread is noticeably slower than the rest. Calling
+/// this at startup on every worker thread moves that cost out of the request
+/// path. Calling it again on a thread that is already initialised does nothing.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::warmup_projection;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// warmup_projection()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Returns
+/// `Ok(())` once both the WGS84-to-BNG and BNG-to-WGS84 objects exist on the
+/// current thread.
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if either [`Proj`] object cannot be
+/// constructed.
+pub fn warmup_projection() -> Result<(), N3gbError> {
+    with_wgs84_to_bng_proj(|_| Ok(()))?;
+    with_bng_to_wgs84_proj(|_| Ok(()))
+}
+
+/// Reports whether the PROJ backend can be used in this process.
+///
+/// Builds the WGS84-to-BNG (`EPSG:4326` to `EPSG:27700`) PROJ pipeline once and
+/// caches whether that succeeded, so later calls are free. Useful as a startup
+/// health check, since PROJ depends on a system library and its database.
+/// This says nothing about OSTN15 conversions, which never need PROJ.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::projection_available;
+///
+/// if !projection_available() {
+///     eprintln!("PROJ is not usable; use ConversionMethod::Ostn15");
+/// }
+/// ```
+///
+/// # Returns
+/// `true` if the PROJ pipeline could be constructed.
+pub fn projection_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| Proj::new_known_crs("EPSG:4326", "EPSG:27700", None).is_ok())
+}
+
+/// Reproject a [`LineString`] from British National Grid to WGS84 using PROJ.
+///
+/// # Arguments
+/// * `line` - The British National Grid line to convert.
+///
+/// # Returns
+/// The line reprojected to WGS84 (longitude, latitude).
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if the PROJ object cannot be built or
+/// the conversion fails for any vertex.
+pub(crate) fn bng_line_to_wgs84(line: &LineString) -> Result<LineString, N3gbError> {
+    let coords: Result<Vec<Coord>, N3gbError> = line
+        .0
+        .par_iter()
+        .map(|c| {
+            with_bng_to_wgs84_proj(|proj| {
+                let (lon, lat) = proj
+                    .convert((c.x, c.y))
+                    .map_err(|e| N3gbError::ProjectionError(e.to_string()))?;
+                Ok(Coord { x: lon, y: lat })
+            })
+        })
+        .collect();
+    Ok(LineString::new(coords?))
 }
 
 /// Run a closure with the thread-local WGS84-to-BNG PROJ object, creating it on
@@ -321,6 +499,31 @@ pub(crate) fn wgs84_line_to_bng_ostn15(line: &LineString) -> Result<LineString,
     Ok(LineString::new(coords?))
 }
 
+/// Reproject a [`LineString`] from British National Grid to WGS84 using OSTN15.
+///
+/// # Arguments
+/// * `line` - The British National Grid line to convert.
+///
+/// # Returns
+/// The line reprojected to WGS84 (longitude, latitude).
+///
+/// # Errors
+/// Returns [`N3gbError::ProjectionError`] if the OSTN15 conversion fails for any
+/// vertex.
+#[cfg(feature = "ostn15")]
+pub(crate) fn bng_line_to_wgs84_ostn15(line: &LineString) -> Result<LineString, N3gbError> {
+    let coords: Result<Vec<Coord>, N3gbError> = line
+        .0
+        .par_iter()
+        .map(|c| {
+            lonlat_bng::convert_osgb36_to_ll(c.x, c.y)
+                .map(|(lon, lat)| Coord { x: lon, y: lat })
+                .map_err(|_| N3gbError::ProjectionError("OSTN15 conversion failed".into()))
+        })
+        .collect();
+    Ok(LineString::new(coords?))
+}
+
 /// Reproject a [`Polygon`] from WGS84 to British National Grid using OSTN15.
 ///
 /// # Arguments
@@ -409,6 +612,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_projection_available() {
+        assert!(projection_available());
+        assert!(projection_available());
+    }
+
+    #[test]
+    fn test_warmup_projection() -> Result<(), N3gbError> {
+        fn proj_ptrs() -> (*const Proj, *const Proj) {
+            let ptr = |cell: &RefCell<Option<Proj>>| {
+                cell.borrow()
+                    .as_ref()
+                    .map_or(std::ptr::null(), |p| p as *const Proj)
+            };
+            (
+                WGS84_TO_BNG_PROJ_OBJECT.with(ptr),
+                BNG_TO_WGS84_PROJ_OBJECT.with(ptr),
+            )
+        }
+
+        warmup_projection()?;
+        let first = proj_ptrs();
+        assert!(!first.0.is_null() && !first.1.is_null());
+
+        warmup_projection()?;
+        assert_eq!(proj_ptrs(), first);
+
+        let bng = wgs84_to_bng(&(-2.2479699500757597, 53.48082746395233))?;
+        assert!(bng.x() > 380000.0 && bng.x() < 390000.0);
+        assert!(bng.y() > 390000.0 && bng.y() < 400000.0);
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "ostn15")]
     fn test_wgs84_to_bng_ostn15() -> Result<(), N3gbError> {
@@ -1,8 +1,10 @@
 mod bng_transformations;
 
 pub(crate) use bng_transformations::{
-    convert_line_to_bng, convert_multipolygon_to_bng, convert_polygon_to_bng, convert_to_bng,
+    convert_line_to_bng, convert_multipolygon_to_bng, convert_polygon_to_bng,
+    convert_polygon_to_wgs84, convert_to_bng, convert_to_wgs84,
 };
+pub use bng_transformations::{projection_available, warmup_projection};
 
 use geo_types::Point;
 
@@ -43,6 +45,20 @@ pub enum ConversionMethod {
     Proj,
 }
 
+/// Backend used where a BNG to WGS84 conversion has no caller-chosen method.
+///
+/// Prefers OSTN15 and falls back to PROJ when the `ostn15` feature is disabled.
+///
+/// # Returns
+/// The [`ConversionMethod`] to use.
+pub(crate) fn preferred_method() -> ConversionMethod {
+    if cfg!(feature = "ostn15") {
+        ConversionMethod::Ostn15
+    } else {
+        ConversionMethod::Proj
+    }
+}
+
 /// Trait for types that can provide x/y coordinates.
 ///
 /// Implemented for `(f64, f64)` tuples and `geo_types::Point<f64>`.
@@ -0,0 +1,144 @@
+use crate::cell::HexCell;
+use crate::error::N3gbError;
+use crate::grid::HexGrid;
+use crate::index::MAX_GRID_CELLS;
+use ndarray::Array2;
+
+impl HexGrid {
+    /// Lays the grid out as a dense 2D array indexed by row and column.
+    ///
+    /// The cell at `(row, col)` is stored at `[row - min_row, col - min_col]`,
+    /// with `None` for lattice positions in the bounding row/column range that
+    /// are not in the grid. Array rows run south to north, so flip the first
+    /// axis to draw the array as an image. Odd rows are offset half a cell east
+    /// on the map, which the array does not show. Requires the `ndarray` feature.
+    ///
+    /// # Returns
+    ///
+    /// The array together with `min_row` and `min_col`, the offsets to add to
+    /// an array index to get back to a cell address. An empty grid gives a
+    /// `0 x 0` array and offsets of zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::TooManyCells`] if the bounding row/column range
+    /// holds more than [`MAX_GRID_CELLS`](crate::MAX_GRID_CELLS) positions, e.g.
+    /// for a grid of a few cells spread across the country.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+    /// let (dense, min_row, min_col) = grid.to_dense_grid()?;
+    ///
+    /// let cell = &grid.cells()[0];
+    /// let index = ((cell.row - min_row) as usize, (cell.col - min_col) as usize);
+    /// assert_eq!(dense[index].as_ref(), Some(cell));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_dense_grid(&self) -> Result<(Array2<Option<HexCell>>, i64, i64), N3gbError> {
+        let Some(first) = self.cells().first() else {
+            return Ok((Array2::default((0, 0)), 0, 0));
+        };
+
+        let (min_row, max_row, min_col, max_col) = self.iter().fold(
+            (first.row, first.row, first.col, first.col),
+            |(min_row, max_row, min_col, max_col), cell| {
+                (
+                    min_row.min(cell.row),
+                    max_row.max(cell.row),
+                    min_col.min(cell.col),
+                    max_col.max(cell.col),
+                )
+            },
+        );
+
+        let (rows, cols) = (
+            (max_row - min_row + 1) as u64,
+            (max_col - min_col + 1) as u64,
+        );
+        let count = rows.saturating_mul(cols);
+        if count > MAX_GRID_CELLS {
+            return Err(N3gbError::TooManyCells(count, MAX_GRID_CELLS));
+        }
+
+        let shape = (rows as usize, cols as usize);
+        let mut dense = Array2::default(shape);
+        for cell in self.iter() {
+            let index = ((cell.row - min_row) as usize, (cell.col - min_col) as usize);
+            dense[index] = Some(cell.clone());
+        }
+
+        Ok((dense, min_row, min_col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::N3gbError;
+    use geo_types::polygon;
+
+    #[test]
+    fn test_to_dense_grid() -> Result<(), N3gbError> {
+        let triangle = polygon![
+            (x: 457000.0, y: 339500.0),
+            (x: 458000.0, y: 339500.0),
+            (x: 457500.0, y: 340500.0),
+            (x: 457000.0, y: 339500.0),
+        ];
+        let grid = HexGrid::from_bng_polygon(&triangle, 10)?;
+        let (dense, min_row, min_col) = grid.to_dense_grid()?;
+
+        assert_eq!(min_row, grid.iter().map(|cell| cell.row).min().unwrap());
+        assert_eq!(min_col, grid.iter().map(|cell| cell.col).min().unwrap());
+
+        for cell in grid.iter() {
+            let index = ((cell.row - min_row) as usize, (cell.col - min_col) as usize);
+            assert_eq!(dense[index].as_ref(), Some(cell));
+        }
+
+        // A triangle leaves some of its bounding range empty
+        let present = dense.iter().filter(|slot| slot.is_some()).count();
+        assert_eq!(present, grid.len());
+        assert!(present < dense.len());
+        for ((i, j), slot) in dense.indexed_iter() {
+            if let Some(cell) = slot {
+                assert_eq!(
+                    (cell.row, cell.col),
+                    (i as i64 + min_row, j as i64 + min_col)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_dense_grid_empty() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?
+            .par_filter(|_| false);
+        let (dense, min_row, min_col) = grid.to_dense_grid()?;
+        assert_eq!(dense.dim(), (0, 0));
+        assert_eq!((min_row, min_col), (0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_dense_grid_too_many_cells() -> Result<(), N3gbError> {
+        // Two cells at opposite corners of the country span a huge range
+        let cells = vec![
+            HexCell::from_bng(&(1000.0, 1000.0), 12)?,
+            HexCell::from_bng(&(650000.0, 1200000.0), 12)?,
+        ];
+        let grid = HexGrid::new(cells, 12);
+        assert!(matches!(
+            grid.to_dense_grid(),
+            Err(N3gbError::TooManyCells(_, MAX_GRID_CELLS))
+        ));
+        Ok(())
+    }
+}
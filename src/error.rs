@@ -23,6 +23,16 @@ pub enum N3gbError {
     GeometryParseError(String),
     /// Grid distance requires both cells to be at the same zoom level.
     ZoomLevelMismatch(u8, u8),
+    /// The requested grid would contain more cells than the allowed maximum.
+    TooManyCells(u64, u64),
+    /// An H3 index or resolution is invalid (requires the `h3` feature).
+    H3Error(String),
+    /// A coordinate lies outside the range the identifier can encode (e.g., negative).
+    OutOfBounds(String),
+    /// The parts of a cell (ID, center, row, column) do not describe the same cell.
+    InconsistentCell(String),
+    /// An argument is not one of the accepted values (e.g., an unknown property name).
+    InvalidArgument(String),
 }
 
 impl std::fmt::Display for N3gbError {
@@ -41,6 +51,13 @@ impl std::fmt::Display for N3gbError {
             N3gbError::ZoomLevelMismatch(a, b) => {
                 write!(f, "Zoom level mismatch: {} vs {}", a, b)
             }
+            N3gbError::TooManyCells(count, limit) => {
+                write!(f, "Too many cells: {} exceeds limit of {}", count, limit)
+            }
+            N3gbError::H3Error(msg) => write!(f, "H3 error: {}", msg),
+            N3gbError::OutOfBounds(msg) => write!(f, "Out of bounds: {}", msg),
+            N3gbError::InconsistentCell(msg) => write!(f, "Inconsistent cell: {}", msg),
+            N3gbError::InvalidArgument(msg) => write!(f, "Invalid argument: {}", msg),
         }
     }
 }
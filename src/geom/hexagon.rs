@@ -4,7 +4,9 @@ use geo_types::{Coord, LineString, Polygon};
 /// Creates a hexagonal polygon centered at the given point.
 ///
 /// Returns a pointy-top hexagon (vertices at top and bottom) with the specified
-/// circumradius (center to vertex distance).
+/// circumradius (center to vertex distance). Vertices are emitted at increasing
+/// angles from 30°, so the exterior ring is always counter-clockwise for a
+/// positive `size`.
 ///
 /// # Arguments
 ///
@@ -13,8 +15,8 @@ use geo_types::{Coord, LineString, Polygon};
 ///
 /// # Returns
 ///
-/// A [`Polygon<f64>`] whose exterior ring has 7 coordinates (6 vertices plus a
-/// repeated first vertex to close the ring).
+/// A [`Polygon<f64>`] whose counter-clockwise exterior ring has 7 coordinates
+/// (6 vertices plus a repeated first vertex to close the ring).
 pub fn create_hexagon<C: Coordinate>(center: &C, size: f64) -> Polygon<f64> {
     let mut coords = Vec::with_capacity(7);
 
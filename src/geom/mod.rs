@@ -2,4 +2,5 @@ mod hexagon;
 mod parse;
 
 pub use hexagon::create_hexagon;
+pub(crate) use parse::parse_geojson;
 pub use parse::parse_geometry;
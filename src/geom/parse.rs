@@ -65,28 +65,84 @@ pub fn parse_geojson(s: &str) -> Result<Geometry<f64>, N3gbError> {
     }
 }
 
-/// Parses a WKT string into a `geo_types::Geometry`.
+/// Parses a WKT or EWKT string into a `geo_types::Geometry`.
+///
+/// An EWKT `SRID=<code>;` prefix is stripped and the SRID ignored, so the
+/// coordinates are interpreted in whichever CRS the caller configures. Z and M
+/// values are dropped to 2D, whether tagged (`POINT Z (x y z)`) or implicit
+/// (`POINT (x y z)`, as written by PostGIS EWKT).
 ///
 /// # Arguments
 ///
-/// * `s` - The WKT string to parse.
+/// * `s` - The WKT or EWKT string to parse.
 ///
 /// # Returns
 ///
-/// The parsed [`Geometry<f64>`].
+/// The parsed 2D [`Geometry<f64>`].
 ///
 /// # Errors
 ///
 /// Returns [`N3gbError::GeometryParseError`] if the string is not valid WKT or
 /// cannot be converted into a geometry.
 pub fn parse_wkt(s: &str) -> Result<Geometry<f64>, N3gbError> {
-    let wkt: Wkt<f64> =
-        Wkt::from_str(s).map_err(|e| N3gbError::GeometryParseError(e.to_string()))?;
+    let s = strip_srid(s.trim())?;
+    let wkt: Wkt<f64> = match Wkt::from_str(s) {
+        Ok(wkt) => wkt,
+        Err(e) => implicit_dimension_wkt(s)
+            .and_then(|tagged| Wkt::from_str(&tagged).ok())
+            .ok_or_else(|| N3gbError::GeometryParseError(e.to_string()))?,
+    };
 
     wkt.try_into()
         .map_err(|_| N3gbError::GeometryParseError("Failed to convert WKT to geometry".to_string()))
 }
 
+/// Strips an EWKT `SRID=<code>;` prefix, if present.
+///
+/// # Arguments
+///
+/// * `s` - The trimmed WKT or EWKT string.
+///
+/// # Returns
+///
+/// The WKT body following the prefix, or `s` unchanged if there is no prefix.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::GeometryParseError`] if the prefix is not terminated by `;`.
+fn strip_srid(s: &str) -> Result<&str, N3gbError> {
+    if !s.get(..5).is_some_and(|p| p.eq_ignore_ascii_case("SRID=")) {
+        return Ok(s);
+    }
+    s.split_once(';')
+        .map(|(_, body)| body.trim_start())
+        .ok_or_else(|| N3gbError::GeometryParseError(format!("Missing ';' after SRID in '{}'", s)))
+}
+
+/// Adds an explicit `Z` or `ZM` tag to WKT whose coordinates carry extra dimensions.
+///
+/// # Arguments
+///
+/// * `s` - WKT without a dimension tag, e.g. `POINT (1 2 3)`.
+///
+/// # Returns
+///
+/// The WKT with a dimension tag inserted after the geometry type, or `None` if the
+/// first coordinate is not 3D or 4D.
+fn implicit_dimension_wkt(s: &str) -> Option<String> {
+    let open = s.find('(')?;
+    let first_coord = s[open..]
+        .trim_start_matches(|c: char| c == '(' || c.is_whitespace())
+        .split([',', ')'])
+        .next()?;
+    let tag = match first_coord.split_whitespace().count() {
+        3 => "Z",
+        4 => "ZM",
+        _ => return None,
+    };
+    Some(format!("{} {} {}", s[..open].trim_end(), tag, &s[open..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +213,38 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_parse_ewkt_srid_prefix() -> Result<(), N3gbError> {
+        let geom = parse_geometry("SRID=27700;POINT(530000 180000)")?;
+        assert_eq!(
+            geom,
+            Geometry::Point(geo_types::Point::new(530000.0, 180000.0))
+        );
+
+        let geom = parse_geometry("srid=4326; LINESTRING(-0.1 51.5, -0.2 51.6)")?;
+        assert!(matches!(geom, Geometry::LineString(ref line) if line.0.len() == 2));
+
+        assert!(parse_geometry("SRID=27700 POINT(530000 180000)").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_wkt_drops_z_and_m() -> Result<(), N3gbError> {
+        let expected = Geometry::Point(geo_types::Point::new(530000.0, 180000.0));
+        assert_eq!(parse_geometry("POINT Z (530000 180000 45)")?, expected);
+        assert_eq!(parse_geometry("POINT ZM (530000 180000 45 1)")?, expected);
+        assert_eq!(
+            parse_geometry("SRID=27700;POINT(530000 180000 45)")?,
+            expected
+        );
+
+        match parse_geometry("LINESTRING (0 0 1, 10 10 2)")? {
+            Geometry::LineString(line) => {
+                assert_eq!(line.0, vec![(0.0, 0.0).into(), (10.0, 10.0).into()]);
+            }
+            _ => panic!("Expected LineString"),
+        }
+        Ok(())
+    }
 }
@@ -1,18 +1,26 @@
-use crate::cell::HexCell;
+use crate::cell::{HexCell, id_hash};
 use crate::coord::{
-    ConversionMethod, Coordinate, convert_multipolygon_to_bng, convert_polygon_to_bng,
-    convert_to_bng,
+    ConversionMethod, Coordinate, Crs, convert_multipolygon_to_bng, convert_polygon_to_bng,
+    convert_to_bng, preferred_method,
 };
 use crate::error::N3gbError;
-use crate::index::{GRID_EXTENTS, generate_hex_identifier, point_to_row_col, row_col_to_center};
+use crate::geom::parse_geojson;
+use crate::index::{
+    CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, MAX_GRID_CELLS, MAX_ZOOM_LEVEL,
+    generate_hex_identifier, grid_cell_identifier, neighbor_row_cols, offset_to_cube,
+    point_to_row_col, row_col_to_center_unchecked,
+};
 use crate::io::arrow::HexCellsToArrow;
+use crate::io::geojson::{cells_to_geojson_simplified, cells_to_geojson_with};
 use crate::io::parquet::HexCellsToGeoParquet;
+use crate::parallel::*;
 use arrow_array::RecordBatch;
-use geo::{BoundingRect, Intersects};
-use geo_types::{MultiPolygon, Point, Polygon, Rect};
+use geo::{Area, BooleanOps, BoundingRect, Centroid, Contains, ConvexHull, Intersects};
+use geo_types::{Geometry, MultiPoint, MultiPolygon, Point, Polygon, Rect};
 use geoarrow_array::array::{PointArray, PolygonArray};
-use rayon::prelude::*;
-use std::collections::HashMap;
+use geojson::GeoJson;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use std::path::Path;
 
 /// A collection of hexagonal cells covering a geographic extent.
@@ -55,7 +63,7 @@ pub struct HexGrid {
 
 impl HexGrid {
     /// Build a `HexGrid` from a vec of cells, constructing the spatial index.
-    fn new(cells: Vec<HexCell>, zoom_level: u8) -> Self {
+    pub(crate) fn new(cells: Vec<HexCell>, zoom_level: u8) -> Self {
         let index = cells
             .iter()
             .enumerate()
@@ -89,6 +97,17 @@ impl HexGrid {
         Ok(Self::new(cells, zoom_level))
     }
 
+    /// Build a grid from an optional bounding rectangle, empty if there is none.
+    ///
+    /// Includes cells straddling the rectangle's edges, so the grid holds every
+    /// cell [`CoverageMode::Exact`] would keep for a geometry with these bounds.
+    fn from_bounding_rect(rect: Option<Rect<f64>>, zoom_level: u8) -> Result<Self, N3gbError> {
+        match rect {
+            Some(rect) => Self::from_rect_with_edges(&rect, zoom_level),
+            None => Ok(Self::new(Vec::new(), zoom_level)),
+        }
+    }
+
     /// Creates a HexGrid from a `geo_types::Rect` in BNG coordinates.
     ///
     /// # Arguments
@@ -114,6 +133,111 @@ impl HexGrid {
         )
     }
 
+    /// Creates a HexGrid covering the whole of [`GRID_EXTENTS`].
+    ///
+    /// Cheap at coarse zoom levels; at fine zoom levels the grid exceeds
+    /// [`MAX_GRID_CELLS`](crate::MAX_GRID_CELLS) and an error is returned instead.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let grid = HexGrid::national(4)?;
+    /// assert!(!grid.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `zoom_level` - The zoom level for the generated cells.
+    ///
+    /// # Returns
+    ///
+    /// A `HexGrid` covering the full British National Grid extent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the
+    /// maximum supported zoom level, or [`N3gbError::TooManyCells`] if the grid
+    /// would be too large to generate.
+    pub fn national(zoom_level: u8) -> Result<Self, N3gbError> {
+        Self::from_extent(
+            GRID_EXTENTS[0],
+            GRID_EXTENTS[1],
+            GRID_EXTENTS[2],
+            GRID_EXTENTS[3],
+            zoom_level,
+        )
+    }
+
+    /// Creates a HexGrid of the cell containing a point plus `k_rings` rings around it.
+    ///
+    /// The result is the hexagonal disk of every cell within `k_rings` steps
+    /// (see [`HexCell::grid_distance`]) of the cell containing `coord`, holding
+    /// `3k(k + 1) + 1` cells, fewer where the disk crosses the grid's lower
+    /// edge. Cells are ordered by row, then column.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let grid = HexGrid::from_center_bng(&(383640.0, 398260.0), 10, 2)?;
+    /// assert_eq!(grid.len(), 19);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `coord` - The BNG coordinate at the center of the disk.
+    /// * `zoom_level` - The zoom level for the generated cells.
+    /// * `k_rings` - The number of rings around the center cell; `0` gives just
+    ///   the center cell.
+    ///
+    /// # Returns
+    ///
+    /// A `HexGrid` containing the center cell and its surrounding rings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the
+    /// maximum supported zoom level, [`N3gbError::OutOfBounds`] if `coord` lies
+    /// outside the grid, or [`N3gbError::TooManyCells`] if the disk would hold
+    /// more than [`MAX_GRID_CELLS`](crate::MAX_GRID_CELLS) cells.
+    pub fn from_center_bng<C: Coordinate>(
+        coord: &C,
+        zoom_level: u8,
+        k_rings: u32,
+    ) -> Result<Self, N3gbError> {
+        let (center_row, center_col) = point_to_row_col(coord, zoom_level)?;
+
+        let k = k_rings as u64;
+        let count = k.saturating_mul(k + 1).saturating_mul(3).saturating_add(1);
+        if count > MAX_GRID_CELLS {
+            return Err(N3gbError::TooManyCells(count, MAX_GRID_CELLS));
+        }
+
+        let k = k_rings as i64;
+        let (q0, r0, _) = offset_to_cube(center_row, center_col);
+        let cells = (-k..=k)
+            .flat_map(|dr| {
+                ((-k).max(-dr - k)..=k.min(k - dr)).map(move |dq| {
+                    let row = r0 + dr;
+                    (row, q0 + dq + row.div_euclid(2))
+                })
+            })
+            .filter_map(|(row, col)| {
+                let (center, id) = cell_center_and_id(row, col, zoom_level)?;
+                Some(HexCell::new(id, center, zoom_level, row, col))
+            })
+            .collect();
+
+        Ok(Self::new(cells, zoom_level))
+    }
+
     /// Create a HexGrid from British National Grid coordinates
     ///
     /// # Example
@@ -142,12 +266,13 @@ impl HexGrid {
     ///
     /// # Returns
     ///
-    /// A `HexGrid` covering the given extent.
+    /// A `HexGrid` covering the given extent, clipped to [`GRID_EXTENTS`].
     ///
     /// # Errors
     ///
     /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the
-    /// maximum supported zoom level.
+    /// maximum supported zoom level, and [`N3gbError::OutOfBounds`] if the
+    /// extent lies wholly outside the grid.
     pub fn from_bng_extent(
         min: &impl Coordinate,
         max: &impl Coordinate,
@@ -191,16 +316,17 @@ impl HexGrid {
     /// # Errors
     ///
     /// Returns [`N3gbError::ProjectionError`] if projecting the corners from
-    /// WGS84 to BNG fails, or [`N3gbError::InvalidZoomLevel`] if `zoom_level`
-    /// exceeds the maximum supported zoom level.
+    /// WGS84 to BNG fails, [`N3gbError::OutOfBounds`] if a projected corner
+    /// falls outside [`GRID_EXTENTS`] (e.g. a mistyped coordinate outside GB),
+    /// or [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the maximum
+    /// supported zoom level.
     pub fn from_wgs84_extent(
         min: &impl Coordinate,
         max: &impl Coordinate,
         zoom_level: u8,
         method: ConversionMethod,
     ) -> Result<Self, N3gbError> {
-        let min_bng = convert_to_bng(min, method)?;
-        let max_bng = convert_to_bng(max, method)?;
+        let (min_bng, max_bng) = project_wgs84_corners(min, max, method)?;
         Self::from_extent(
             min_bng.x(),
             min_bng.y(),
@@ -257,10 +383,82 @@ impl HexGrid {
             None => return Ok(Self::new(Vec::new(), zoom_level)),
         };
 
-        Ok(Self::from_rect(&bbox, zoom_level)?
+        Ok(Self::from_rect_with_edges(&bbox, zoom_level)?
             .retain(|cell| polygon.intersects(&cell.to_polygon())))
     }
 
+    /// Lazily yields the cells intersecting a polygon in BNG coordinates.
+    ///
+    /// Produces the same cells as [`HexGrid::from_bng_polygon`], but one at a
+    /// time and sequentially, so each cell can be processed and dropped without
+    /// holding the whole grid in memory. Not subject to the
+    /// [`MAX_GRID_CELLS`](crate::MAX_GRID_CELLS) limit.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    /// use geo_types::{Polygon, LineString, coord};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![
+    ///         coord! { x: 457000.0, y: 339500.0 },
+    ///         coord! { x: 458000.0, y: 339500.0 },
+    ///         coord! { x: 458000.0, y: 340500.0 },
+    ///         coord! { x: 457000.0, y: 340500.0 },
+    ///         coord! { x: 457000.0, y: 339500.0 },
+    ///     ]),
+    ///     vec![],
+    /// );
+    /// let count = HexGrid::iter_bng_polygon_cells(&polygon, 10)?.count();
+    /// assert!(count > 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - The polygon, in BNG (EPSG:27700) coordinates.
+    /// * `zoom_level` - The zoom level for the generated cells.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the cells whose hexagon intersects the polygon. Empty if
+    /// the polygon has no bounding rectangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds the
+    /// maximum supported zoom level.
+    pub fn iter_bng_polygon_cells(
+        polygon: &Polygon<f64>,
+        zoom_level: u8,
+    ) -> Result<impl Iterator<Item = HexCell>, N3gbError> {
+        // Pad the corner range by one, as hexagons from neighbouring rows and
+        // columns can reach into the polygon's bounding rectangle
+        let (min_row, max_row, min_col, max_col) = match polygon.bounding_rect() {
+            Some(rect) => {
+                let (min_row, max_row, min_col, max_col) = extent_row_col_range(
+                    rect.min().x,
+                    rect.min().y,
+                    rect.max().x,
+                    rect.max().y,
+                    zoom_level,
+                )?;
+                (min_row - 1, max_row + 1, min_col - 1, max_col + 1)
+            }
+            None => (0, -1, 0, -1),
+        };
+
+        Ok((min_row..=max_row)
+            .flat_map(move |row| (min_col..=max_col).map(move |col| (row, col)))
+            .filter_map(move |(row, col)| {
+                let (center, id) = cell_center_and_id(row, col, zoom_level)?;
+                Some(HexCell::new(id, center, zoom_level, row, col))
+            })
+            .filter(move |cell| polygon.intersects(&cell.to_polygon())))
+    }
+
     /// Creates a HexGrid from a polygon in WGS84 (lon/lat) coordinates.
     ///
     /// Projects the polygon to BNG, then generates hex cells for the
@@ -375,7 +573,7 @@ impl HexGrid {
             None => return Ok(Self::new(Vec::new(), zoom_level)),
         };
 
-        Ok(Self::from_rect(&bbox, zoom_level)?
+        Ok(Self::from_rect_with_edges(&bbox, zoom_level)?
             .retain(|cell| multipolygon.intersects(&cell.to_polygon())))
     }
 
@@ -442,6 +640,145 @@ impl HexGrid {
         Self::from_bng_multipolygon(&bng_multipolygon, zoom_level)
     }
 
+    /// Creates a HexGrid from a GeoJSON polygon or multipolygon in WGS84.
+    ///
+    /// Accepts a bare `Polygon` or `MultiPolygon` geometry, or a `Feature`
+    /// wrapping one, then builds the same coverage as
+    /// [`HexGrid::from_wgs84_polygon`] / [`HexGrid::from_wgs84_multipolygon`]
+    /// using the preferred conversion method (OSTN15 when the `ostn15` feature
+    /// is enabled, PROJ otherwise).
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let geojson = r#"{"type":"Polygon","coordinates":[[[-2.25,53.47],[-2.23,53.47],[-2.23,53.49],[-2.25,53.47]]]}"#;
+    /// let grid = HexGrid::from_geojson_str(geojson, 10)?;
+    /// assert!(!grid.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The GeoJSON string, with coordinates in WGS84 (lon/lat).
+    /// * `zoom_level` - The zoom level for the generated cells.
+    ///
+    /// # Returns
+    ///
+    /// A `HexGrid` containing the cells whose hexagon intersects the geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::GeometryParseError`] if the string is not valid
+    /// GeoJSON or is not a polygon or multipolygon, [`N3gbError::ProjectionError`]
+    /// if projecting to BNG fails, or [`N3gbError::InvalidZoomLevel`] if
+    /// `zoom_level` exceeds the maximum supported zoom level.
+    pub fn from_geojson_str(s: &str, zoom_level: u8) -> Result<Self, N3gbError> {
+        let method = preferred_method();
+        match parse_geojson(s)? {
+            Geometry::Polygon(polygon) => Self::from_wgs84_polygon(&polygon, zoom_level, method),
+            Geometry::MultiPolygon(multipolygon) => {
+                Self::from_wgs84_multipolygon(&multipolygon, zoom_level, method)
+            }
+            other => Err(N3gbError::GeometryParseError(format!(
+                "Expected a Polygon or MultiPolygon, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Creates a HexGrid from the cells of a BNG geometry.
+    ///
+    /// Wraps [`HexCell::from_geometry`] and drops repeated cells, so a geometry
+    /// that crosses the same cell more than once (e.g. a self-overlapping
+    /// `MultiLineString`) yields each cell once, in order of first appearance.
+    /// Polygons are reduced to their centroid cell as in
+    /// [`HexCell::from_geometry`]; use [`HexGrid::from_bng_polygon`] for full
+    /// polygon coverage.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    /// use n3gb_rs::geo_types::{Geometry, LineString};
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let line = LineString::from(vec![(383000.0, 398000.0), (386000.0, 398000.0)]);
+    /// let grid = HexGrid::from_bng_geometry(Geometry::LineString(line), 10)?;
+    /// assert!(!grid.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `geom` - The geometry, in BNG (EPSG:27700) coordinates.
+    /// * `zoom_level` - The zoom level for the generated cells.
+    ///
+    /// # Returns
+    ///
+    /// A `HexGrid` at `zoom_level` containing each distinct cell of the geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`HexCell::from_geometry`].
+    pub fn from_bng_geometry(geom: Geometry<f64>, zoom_level: u8) -> Result<Self, N3gbError> {
+        Self::from_geometry(geom, zoom_level, Crs::Bng, ConversionMethod::default())
+    }
+
+    /// Creates a HexGrid from the cells of a WGS84 geometry.
+    ///
+    /// Same as [`HexGrid::from_bng_geometry`], but projects the geometry from
+    /// WGS84 (lon/lat) to BNG first.
+    ///
+    /// # Arguments
+    ///
+    /// * `geom` - The geometry, in WGS84 (lon/lat) coordinates.
+    /// * `zoom_level` - The zoom level for the generated cells.
+    /// * `method` - The coordinate conversion method used to project WGS84 to BNG.
+    ///
+    /// # Returns
+    ///
+    /// A `HexGrid` at `zoom_level` containing each distinct cell of the geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`HexCell::from_geometry`].
+    pub fn from_wgs84_geometry(
+        geom: Geometry<f64>,
+        zoom_level: u8,
+        method: ConversionMethod,
+    ) -> Result<Self, N3gbError> {
+        Self::from_geometry(geom, zoom_level, Crs::Wgs84, method)
+    }
+
+    /// Build a grid from [`HexCell::from_geometry`], keeping the first of any repeated cells.
+    fn from_geometry(
+        geom: Geometry<f64>,
+        zoom_level: u8,
+        crs: Crs,
+        method: ConversionMethod,
+    ) -> Result<Self, N3gbError> {
+        let mut seen = HashSet::new();
+        let cells = HexCell::from_geometry(geom, zoom_level, crs, method)?
+            .into_iter()
+            .filter(|cell| seen.insert(cell.id.clone()))
+            .collect();
+        Ok(Self::new(cells, zoom_level))
+    }
+
+    /// Builds the candidate grid for a geometry with the given bounding rectangle.
+    ///
+    /// The corner range alone can miss a hexagon reaching into the rectangle
+    /// from a neighbouring row or column, e.g. for a polygon smaller than a
+    /// cell, so cells straddling the rectangle's edges are included too.
+    fn from_rect_with_edges(rect: &Rect<f64>, zoom_level: u8) -> Result<Self, N3gbError> {
+        let (min, max) = (rect.min(), rect.max());
+        let cells = generate_edge_cells_for_extent(min.x, min.y, max.x, max.y, zoom_level)?;
+        Ok(Self::new(cells, zoom_level))
+    }
+
     /// Keeps only cells matching the predicate, rebuilding the spatial index.
     fn retain<F>(self, predicate: F) -> Self
     where
@@ -500,6 +837,20 @@ impl HexGrid {
         self.cells.iter()
     }
 
+    /// Returns a parallel iterator over the cells in this grid.
+    ///
+    /// Lets expensive per-cell work be mapped and folded on rayon without first
+    /// copying the cells into a new collection. Only available with the
+    /// `parallel` feature.
+    ///
+    /// # Returns
+    ///
+    /// An indexed rayon iterator yielding a reference to each cell, in grid order.
+    #[cfg(feature = "parallel")]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &HexCell> {
+        self.cells.par_iter()
+    }
+
     /// Looks up which hex cell a point falls in.
     ///
     /// Converts the point to a grid `(row, col)` address, then uses the
@@ -533,6 +884,39 @@ impl HexGrid {
             .collect()
     }
 
+    /// Pairs each cell with its hexagonal polygon.
+    ///
+    /// Builds every polygon once, in parallel when the `parallel` feature is
+    /// enabled, so callers that need both a cell's attributes and its geometry
+    /// do not have to call [`HexCell::to_polygon`] again per export.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(cell, polygon)` pairs in the same order as
+    /// [`HexGrid::cells`]; the polygons match [`HexGrid::to_polygons`].
+    pub fn cells_with_polygons(&self) -> Vec<(&HexCell, Polygon<f64>)> {
+        self.cells
+            .par_iter()
+            .map(|cell| (cell, cell.to_polygon()))
+            .collect()
+    }
+
+    /// Returns the convex hull of the cell centers.
+    ///
+    /// Useful as a quick outline of a grid's coverage, e.g. for map overlays.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Polygon)` in BNG coordinates enclosing every cell center, or `None`
+    /// if the grid has fewer than 3 cells.
+    pub fn convex_hull(&self) -> Option<Polygon<f64>> {
+        if self.cells.len() < 3 {
+            return None;
+        }
+        let centers: MultiPoint<f64> = self.cells.iter().map(|cell| cell.center).collect();
+        Some(centers.convex_hull())
+    }
+
     /// Returns cells matching the given predicate.
     ///
     /// # Arguments
@@ -550,52 +934,477 @@ impl HexGrid {
         self.cells.iter().filter(|cell| predicate(cell)).collect()
     }
 
-    /// Converts all cell centers to an Arrow PointArray.
+    /// Returns a lazy iterator over the cells matching the given predicate.
+    ///
+    /// Yields the same cells as [`HexGrid::filter`] without collecting them
+    /// into a `Vec`, so further iterator adapters can be chained directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure called with each cell; cells for which it
+    ///   returns `true` are yielded.
     ///
     /// # Returns
     ///
-    /// A [`PointArray`] containing the center point of each cell in this grid.
-    pub fn to_arrow_points(&self) -> PointArray {
-        self.cells.to_arrow_points()
+    /// An iterator yielding a reference to each matching cell, in grid order.
+    pub fn iter_filter<F>(&self, predicate: F) -> impl Iterator<Item = &HexCell>
+    where
+        F: Fn(&HexCell) -> bool,
+    {
+        self.cells.iter().filter(move |cell| predicate(cell))
     }
 
-    /// Converts all cells to an Arrow PolygonArray.
+    /// Returns a new grid containing the cells matching the given predicate.
+    ///
+    /// Unlike [`HexGrid::filter`], the predicate is evaluated in parallel and
+    /// the result is an owned grid with its own spatial index, which suits
+    /// expensive predicates such as containment tests against a polygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure called with each cell; cells for which it
+    ///   returns `true` are kept.
     ///
     /// # Returns
     ///
-    /// A [`PolygonArray`] containing the hexagonal polygon for each cell in
-    /// this grid.
-    pub fn to_arrow_polygons(&self) -> PolygonArray {
-        self.cells.to_arrow_polygons()
+    /// A `HexGrid` at the same zoom level holding clones of the matching cells.
+    pub fn par_filter<F>(&self, predicate: F) -> HexGrid
+    where
+        F: Fn(&HexCell) -> bool + Sync,
+    {
+        let cells: Vec<HexCell> = self
+            .cells
+            .par_iter()
+            .filter(|cell| predicate(cell))
+            .cloned()
+            .collect();
+        Self::new(cells, self.zoom_level)
     }
 
-    /// Converts all cells to an Arrow RecordBatch with all attributes.
+    /// Returns a new grid containing only the cells intersecting a polygon.
     ///
-    /// # Returns
+    /// Lets a grid generated quickly over a rectangle be clipped to an irregular
+    /// boundary afterwards without regenerating it. Clipping a grid that covers
+    /// the polygon's bounding box, including the cells straddling its edges
+    /// (see [`HexGridBuilder::include_edge_cells`]), gives the same cells as
+    /// [`HexGrid::from_bng_polygon`]. Cells are tested in parallel.
     ///
-    /// A [`RecordBatch`] containing every cell's attributes.
+    /// # Arguments
     ///
-    /// # Errors
+    /// * `poly` - The clipping polygon, in BNG (EPSG:27700) coordinates.
     ///
-    /// Returns [`N3gbError::IoError`] if the record batch cannot be
-    /// constructed.
-    pub fn to_record_batch(&self) -> Result<RecordBatch, N3gbError> {
-        self.cells.to_record_batch()
+    /// # Returns
+    ///
+    /// A `HexGrid` at the same zoom level holding clones of the cells whose
+    /// hexagon intersects the polygon.
+    pub fn clip_to_bng_polygon(&self, poly: &Polygon<f64>) -> HexGrid {
+        self.par_filter(|cell| poly.intersects(&cell.to_polygon()))
     }
 
-    /// Writes all cells to a GeoParquet file.
+    /// Returns a reproducible subsample of at most `n` cells.
+    ///
+    /// Each cell is ranked by a hash of its ID mixed with `seed`, and the `n`
+    /// lowest-ranked cells are kept. The hash is FNV-1a, so the same grid,
+    /// `n` and `seed` always give the same sample, across runs and platforms.
+    /// Useful for previewing coverage without rendering every cell.
     ///
     /// # Arguments
     ///
-    /// * `path` - The filesystem path to write the GeoParquet file to.
+    /// * `n` - The maximum number of cells to keep.
+    /// * `seed` - Seed mixed into the hash; different seeds give different samples.
     ///
     /// # Returns
     ///
-    /// `()` on success, once all cells have been written to the file.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`N3gbError::IoError`] if the file cannot be written.
+    /// A `HexGrid` at the same zoom level holding `min(n, len)` cells, in the
+    /// order they appear in this grid.
+    pub fn sample(&self, n: usize, seed: u64) -> HexGrid {
+        if n >= self.cells.len() {
+            return Self::new(self.cells.clone(), self.zoom_level);
+        }
+
+        let mut ranked: Vec<(u64, usize)> = self
+            .cells
+            .par_iter()
+            .enumerate()
+            .map(|(i, cell)| (id_hash(&cell.id, seed), i))
+            .collect();
+        ranked.sort_unstable();
+
+        let mut picked: Vec<usize> = ranked[..n].iter().map(|&(_, i)| i).collect();
+        picked.sort_unstable();
+
+        let cells = picked.into_iter().map(|i| self.cells[i].clone()).collect();
+        Self::new(cells, self.zoom_level)
+    }
+
+    /// Splits the grid into sub-grids keyed by a function of each cell.
+    ///
+    /// Useful for sharded writes, e.g. partitioning by [`HexCell::os_tile`] or
+    /// by an easting band. Each sub-grid keeps this grid's zoom level and the
+    /// relative order of its cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Function returning the partition key for a cell.
+    ///
+    /// # Returns
+    ///
+    /// A map from each distinct key to a `HexGrid` holding the cells with that
+    /// key. An empty grid gives an empty map.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+    /// let bands = grid.partition_by(|cell| (cell.easting() / 500.0) as u32);
+    /// assert_eq!(bands.values().map(HexGrid::len).sum::<usize>(), grid.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition_by<K, F>(&self, key: F) -> HashMap<K, HexGrid>
+    where
+        K: Eq + Hash,
+        F: Fn(&HexCell) -> K,
+    {
+        let mut groups: HashMap<K, Vec<HexCell>> = HashMap::new();
+        for cell in &self.cells {
+            groups.entry(key(cell)).or_default().push(cell.clone());
+        }
+        groups
+            .into_iter()
+            .map(|(k, cells)| (k, Self::new(cells, self.zoom_level)))
+            .collect()
+    }
+
+    /// Returns the cells that straddle a polygon's boundary.
+    ///
+    /// A cell is included when its hexagon intersects the polygon but is not
+    /// fully contained by it, i.e. it is neither wholly inside nor wholly
+    /// outside. Useful for perimeter analysis.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polygon, in BNG (EPSG:27700) coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A vector of references to the cells whose hexagon crosses the polygon's
+    /// boundary (exterior or interior rings).
+    pub fn boundary_cells(&self, poly: &Polygon<f64>) -> Vec<&HexCell> {
+        self.cells
+            .par_iter()
+            .filter(|cell| {
+                let hex = cell.to_polygon();
+                poly.intersects(&hex) && !poly.contains(&hex)
+            })
+            .collect()
+    }
+
+    /// Returns the cells whose hexagons touch a circular buffer around a point.
+    ///
+    /// Unlike filtering on centre distance, this tests the true cell geometry,
+    /// so a cell is included when any part of its hexagon lies within
+    /// `radius_m` of `center`, even if its centre is further away. The buffer
+    /// is approximated by a 64-sided polygon with its vertices on the circle.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The centre of the buffer, in BNG (EPSG:27700) coordinates.
+    /// * `radius_m` - The buffer radius in metres.
+    ///
+    /// # Returns
+    ///
+    /// A vector of references to the cells whose hexagon intersects the buffer,
+    /// in grid order. Empty if `radius_m` is negative or not finite.
+    pub fn cells_intersecting_buffer(&self, center: &Point<f64>, radius_m: f64) -> Vec<&HexCell> {
+        if !radius_m.is_finite() || radius_m < 0.0 {
+            return Vec::new();
+        }
+
+        const SEGMENTS: usize = 64;
+        let ring: Vec<(f64, f64)> = (0..=SEGMENTS)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * (i % SEGMENTS) as f64 / SEGMENTS as f64;
+                (
+                    center.x() + radius_m * angle.cos(),
+                    center.y() + radius_m * angle.sin(),
+                )
+            })
+            .collect();
+        let buffer = Polygon::new(ring.into(), vec![]);
+
+        self.cells
+            .par_iter()
+            .filter(|cell| buffer.intersects(&cell.to_polygon()))
+            .collect()
+    }
+
+    /// Splits a value across the cells overlapping a polygon, in proportion to
+    /// the area of overlap.
+    ///
+    /// Useful for distributing statistics reported for an area (e.g. an
+    /// administrative boundary) onto the hex grid. Shares are normalised over
+    /// the overlapping cells, so they always sum to `value`, even if the grid
+    /// does not cover the whole polygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polygon, in BNG (EPSG:27700) coordinates.
+    /// * `value` - The total value to distribute.
+    ///
+    /// # Returns
+    ///
+    /// Each cell with a non-zero overlap paired with its share of `value`, in
+    /// grid order. Empty if no cell overlaps the polygon.
+    pub fn disaggregate(&self, poly: &Polygon<f64>, value: f64) -> Vec<(&HexCell, f64)> {
+        let overlaps: Vec<(&HexCell, f64)> = self
+            .cells
+            .par_iter()
+            .filter_map(|cell| {
+                let hex = cell.to_polygon();
+                if !poly.intersects(&hex) {
+                    return None;
+                }
+                let area = hex.intersection(poly).unsigned_area();
+                (area > 0.0).then_some((cell, area))
+            })
+            .collect();
+
+        let total: f64 = overlaps.iter().map(|(_, area)| area).sum();
+        overlaps
+            .into_iter()
+            .map(|(cell, area)| (cell, value * area / total))
+            .collect()
+    }
+
+    /// Returns the grid's adjacency as an undirected edge list.
+    ///
+    /// Each edge is a pair of indices into [`HexGrid::cells`] for two
+    /// neighbouring cells that are both present in the grid. Every edge is
+    /// listed once, with the smaller index first.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(usize, usize)` index pairs, one per pair of adjacent cells.
+    pub fn adjacency(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for (i, cell) in self.cells.iter().enumerate() {
+            for neighbor in neighbor_row_cols(cell.row, cell.col) {
+                if let Some(&j) = self.index.get(&neighbor)
+                    && i < j
+                {
+                    edges.push((i, j));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns each cell's in-grid neighbours keyed by cell ID.
+    ///
+    /// Precomputes the neighbour lookup once for graph-heavy workloads. Only
+    /// neighbours that are present in this grid are included, so cells on the
+    /// edge of the grid have fewer than six entries.
+    ///
+    /// # Returns
+    ///
+    /// A map from every cell ID in the grid to the IDs of its neighbouring cells.
+    pub fn neighbor_map(&self) -> HashMap<String, Vec<String>> {
+        self.cells
+            .iter()
+            .map(|cell| {
+                let neighbors = neighbor_row_cols(cell.row, cell.col)
+                    .iter()
+                    .filter_map(|neighbor| self.index.get(neighbor))
+                    .map(|&j| self.cells[j].id.clone())
+                    .collect();
+                (cell.id.clone(), neighbors)
+            })
+            .collect()
+    }
+
+    /// Returns the cells along the seam between this grid and another.
+    ///
+    /// Useful when stitching adjacent tiles: the result holds every cell that
+    /// is in both grids, plus every cell of either grid that neighbours a cell
+    /// of the other. Cells from this grid come first, in grid order, followed
+    /// by the remaining cells from `other`; no cell appears twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The adjacent grid, at the same zoom level.
+    ///
+    /// # Returns
+    ///
+    /// The seam cells, empty if the grids neither overlap nor touch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ZoomLevelMismatch`] if the grids are at different
+    /// zoom levels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let west = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+    /// let east = HexGrid::from_bng_extent(&(458000.0, 339500.0), &(459000.0, 340500.0), 10)?;
+    ///
+    /// let seam = west.shared_boundary(&east)?;
+    /// assert!(!seam.is_empty() && seam.len() < west.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shared_boundary(&self, other: &HexGrid) -> Result<Vec<HexCell>, N3gbError> {
+        if self.zoom_level != other.zoom_level {
+            return Err(N3gbError::ZoomLevelMismatch(
+                self.zoom_level,
+                other.zoom_level,
+            ));
+        }
+
+        let touches = |cell: &HexCell, grid: &HexGrid| {
+            grid.index.contains_key(&(cell.row, cell.col))
+                || neighbor_row_cols(cell.row, cell.col)
+                    .iter()
+                    .any(|neighbor| grid.index.contains_key(neighbor))
+        };
+
+        let mut cells: Vec<HexCell> = self
+            .cells
+            .iter()
+            .filter(|cell| touches(cell, other))
+            .cloned()
+            .collect();
+        cells.extend(
+            other
+                .cells
+                .iter()
+                .filter(|cell| {
+                    !self.index.contains_key(&(cell.row, cell.col)) && touches(cell, self)
+                })
+                .cloned(),
+        );
+        Ok(cells)
+    }
+
+    /// Converts all cell centers to an Arrow PointArray.
+    ///
+    /// # Returns
+    ///
+    /// A [`PointArray`] containing the center point of each cell in this grid.
+    pub fn to_arrow_points(&self) -> PointArray {
+        self.cells.to_arrow_points()
+    }
+
+    /// Converts all cells to an Arrow PolygonArray.
+    ///
+    /// # Returns
+    ///
+    /// A [`PolygonArray`] containing the hexagonal polygon for each cell in
+    /// this grid.
+    pub fn to_arrow_polygons(&self) -> PolygonArray {
+        self.cells.to_arrow_polygons()
+    }
+
+    /// Converts all cells to an Arrow RecordBatch with all attributes.
+    ///
+    /// # Returns
+    ///
+    /// A [`RecordBatch`] containing every cell's attributes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the record batch cannot be
+    /// constructed.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, N3gbError> {
+        self.cells.to_record_batch()
+    }
+
+    /// Converts all cells to GeoJSON, drawing cells too small to see as points.
+    ///
+    /// A cell renders `width / map_scale` pixels wide. Cells narrower than
+    /// `min_cell_px` on screen are written as their center point instead of a
+    /// hexagon, which keeps exports of millions of fine cells light. Output is
+    /// in WGS84, like [`HexCellsToGeoJson::to_geojson_value`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min_cell_px` - The smallest on-screen width, in pixels, drawn as a hexagon.
+    /// * `map_scale` - The ground distance covered by one pixel, in meters.
+    ///
+    /// # Returns
+    ///
+    /// A [`GeoJson::FeatureCollection`] with one `Point` or `Polygon` feature
+    /// per cell, carrying `id`, `zoom_level`, `row`, and `col` properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ProjectionError`] if a cell cannot be reprojected
+    /// to WGS84.
+    ///
+    /// [`HexCellsToGeoJson::to_geojson_value`]: crate::HexCellsToGeoJson::to_geojson_value
+    pub fn to_geojson_simplified(
+        &self,
+        min_cell_px: f64,
+        map_scale: f64,
+    ) -> Result<GeoJson, N3gbError> {
+        cells_to_geojson_simplified(&self.cells, min_cell_px, map_scale)
+    }
+
+    /// Converts all cells to GeoJSON, keeping only the chosen properties.
+    ///
+    /// Leaving out properties you do not need keeps large exports lean. Output
+    /// is in WGS84, like [`HexCellsToGeoJson::to_geojson_value`].
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexGrid;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+    /// let geojson = grid.to_geojson_with(&["id", "easting", "northing"])?;
+    /// assert!(geojson.to_string().contains("\"easting\""));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `props` - The properties to write, from `id`, `zoom_level`, `row`,
+    ///   `col`, `easting` and `northing`. An empty slice writes all of them.
+    ///
+    /// # Returns
+    ///
+    /// A [`GeoJson::FeatureCollection`] with one hexagon polygon per cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::InvalidArgument`] if a property name is not
+    /// recognised, or [`N3gbError::ProjectionError`] if a cell cannot be reprojected to WGS84.
+    ///
+    /// [`HexCellsToGeoJson::to_geojson_value`]: crate::HexCellsToGeoJson::to_geojson_value
+    pub fn to_geojson_with(&self, props: &[&str]) -> Result<GeoJson, N3gbError> {
+        cells_to_geojson_with(&self.cells, props)
+    }
+
+    /// Writes all cells to a GeoParquet file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The filesystem path to write the GeoParquet file to.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success, once all cells have been written to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the file cannot be written.
     pub fn to_geoparquet(&self, path: impl AsRef<Path>) -> Result<(), N3gbError> {
         self.cells.to_geoparquet(path)
     }
@@ -619,6 +1428,18 @@ impl IntoIterator for HexGrid {
     }
 }
 
+/// How a [`HexGridBuilder`] fills a polygon or multipolygon with cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoverageMode {
+    /// Keep only the cells whose hexagon intersects the geometry.
+    #[default]
+    Exact,
+    /// Keep every cell covering the geometry's bounding box, including cells
+    /// straddling its edges, skipping the per-cell intersection test. Faster, but includes cells outside the
+    /// geometry unless it is roughly rectangular.
+    BoundingBox,
+}
+
 /// Builder for constructing a [`HexGrid`].
 ///
 /// Remember that the builder struct is there to collect and normalise inputs (converting to BNG if needed)
@@ -650,6 +1471,9 @@ pub struct HexGridBuilder {
     polygon: Option<Polygon<f64>>,
     multipolygon: Option<MultiPolygon<f64>>,
     conversion_method: ConversionMethod,
+    coverage: CoverageMode,
+    guarantee_nonempty: bool,
+    include_edge_cells: bool,
 }
 
 impl HexGridBuilder {
@@ -693,6 +1517,66 @@ impl HexGridBuilder {
         self
     }
 
+    /// Sets how a polygon or multipolygon is filled with cells.
+    ///
+    /// Defaults to [`CoverageMode::Exact`]. Has no effect when building from an
+    /// extent.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The coverage strategy to use.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder, for chaining.
+    pub fn coverage(mut self, mode: CoverageMode) -> Self {
+        self.coverage = mode;
+        self
+    }
+
+    /// Sets whether a polygon or multipolygon always maps to at least one cell.
+    ///
+    /// Both coverage modes consider every cell whose hexagon reaches into the
+    /// geometry's bounding box, so a non-empty polygon inside the grid, however
+    /// small, always selects at least one cell. The flag is a safeguard for
+    /// degenerate input the intersection test cannot place: if the grid comes
+    /// back empty, it falls back to the single cell containing the geometry's
+    /// centroid, so no input feature is lost. Defaults to `false`. Has no
+    /// effect when building from an extent.
+    ///
+    /// # Arguments
+    ///
+    /// * `guarantee` - Whether to fall back to the centroid cell.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder, for chaining.
+    pub fn guarantee_nonempty(mut self, guarantee: bool) -> Self {
+        self.guarantee_nonempty = guarantee;
+        self
+    }
+
+    /// Sets whether an extent also keeps cells straddling its edges.
+    ///
+    /// By default an extent is covered by the row/column range spanned by its
+    /// corner cells, which can leave a ragged boundary: cells whose centre lies
+    /// just outside the extent but whose hexagon reaches into it are dropped.
+    /// When enabled, every cell whose hexagon intersects the extent is kept as
+    /// well. Defaults to `false`. Has no effect when building from a polygon or
+    /// multipolygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to keep cells that straddle the extent's edges.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder, for chaining.
+    pub fn include_edge_cells(mut self, include: bool) -> Self {
+        self.include_edge_cells = include;
+        self
+    }
+
     /// Sets the extent from a `geo_types::Rect` in BNG coordinates.
     ///
     /// # Arguments
@@ -768,14 +1652,14 @@ impl HexGridBuilder {
     /// # Errors
     ///
     /// Returns [`N3gbError::ProjectionError`] if projecting the corners from
-    /// WGS84 to BNG fails.
+    /// WGS84 to BNG fails, or [`N3gbError::OutOfBounds`] if a projected corner
+    /// falls outside [`GRID_EXTENTS`].
     pub fn wgs84_extent(
         mut self,
         min: &impl Coordinate,
         max: &impl Coordinate,
     ) -> Result<Self, N3gbError> {
-        let min_bng = convert_to_bng(min, self.conversion_method)?;
-        let max_bng = convert_to_bng(max, self.conversion_method)?;
+        let (min_bng, max_bng) = project_wgs84_corners(min, max, self.conversion_method)?;
         self.min_x = Some(min_bng.x());
         self.min_y = Some(min_bng.y());
         self.max_x = Some(max_bng.x());
@@ -1004,10 +1888,23 @@ impl HexGridBuilder {
     pub fn build(self) -> Result<HexGrid, N3gbError> {
         let zoom_level = self.zoom_level.expect("zoom_level must be set");
 
-        match (self.multipolygon, self.polygon) {
-            (Some(mp), _) => HexGrid::from_bng_multipolygon(&mp, zoom_level),
-            (_, Some(p)) => HexGrid::from_bng_polygon(&p, zoom_level),
-            (None, None) => {
+        let fallback = match (&self.multipolygon, &self.polygon) {
+            _ if !self.guarantee_nonempty => None,
+            (Some(mp), _) => mp.centroid(),
+            (_, Some(p)) => p.centroid(),
+            (None, None) => None,
+        };
+
+        let grid = match (self.multipolygon, self.polygon, self.coverage) {
+            (Some(mp), _, CoverageMode::Exact) => HexGrid::from_bng_multipolygon(&mp, zoom_level),
+            (Some(mp), _, CoverageMode::BoundingBox) => {
+                HexGrid::from_bounding_rect(mp.bounding_rect(), zoom_level)
+            }
+            (_, Some(p), CoverageMode::Exact) => HexGrid::from_bng_polygon(&p, zoom_level),
+            (_, Some(p), CoverageMode::BoundingBox) => {
+                HexGrid::from_bounding_rect(p.bounding_rect(), zoom_level)
+            }
+            (None, None, _) => {
                 let min_x = self
                     .min_x
                     .expect("extent, polygon, or multipolygon must be set");
@@ -1020,36 +1917,120 @@ impl HexGridBuilder {
                 let max_y = self
                     .max_y
                     .expect("extent, polygon, or multipolygon must be set");
-                HexGrid::from_extent(min_x, min_y, max_x, max_y, zoom_level)
+                if self.include_edge_cells {
+                    generate_edge_cells_for_extent(min_x, min_y, max_x, max_y, zoom_level)
+                        .map(|cells| HexGrid::new(cells, zoom_level))
+                } else {
+                    HexGrid::from_extent(min_x, min_y, max_x, max_y, zoom_level)
+                }
             }
+        }?;
+
+        match fallback {
+            Some(centroid) if grid.is_empty() => Ok(HexGrid::new(
+                vec![HexCell::from_bng(&centroid, zoom_level)?],
+                zoom_level,
+            )),
+            _ => Ok(grid),
         }
     }
 }
 
-/// Generates all hex cells that cover a bounding box.
+/// Projects the corners of a WGS84 extent to BNG, checking both land in the grid.
 ///
-/// This is the single entry point for all grid construction. Every public
-/// constructor (`from_bng_extent`, `from_rect`, `from_bng_polygon`, etc.)
-/// ultimately calls this function.
+/// # Arguments
 ///
-/// ## How it works
+/// * `min` - The minimum (lower-left) corner, in WGS84 (lon/lat) coordinates.
+/// * `max` - The maximum (upper-right) corner, in WGS84 (lon/lat) coordinates.
+/// * `method` - The conversion backend used to project from WGS84 to BNG.
 ///
-/// 1. Converts the four corners of the bounding box to grid `(row, col)` addresses.
-/// 2. Takes the min/max of those to get the full row and column range.
-/// 3. Iterates every `(row, col)` pair in that range (in parallel via Rayon).
-/// 4. For each pair, computes the hex center point and generates a `HexCell`.
-/// 5. Filters out any cells whose center falls outside the BNG grid extents.
+/// # Returns
 ///
-/// ## Errors
+/// The projected `(min, max)` corners in BNG coordinates.
 ///
-/// Returns `Err(InvalidZoomLevel)` if `zoom_level` exceeds `MAX_ZOOM_LEVEL`.
-fn generate_cells_for_extent(
+/// # Errors
+///
+/// Returns [`N3gbError::ProjectionError`] if projecting a corner fails, or
+/// [`N3gbError::OutOfBounds`] if a projected corner falls outside
+/// [`GRID_EXTENTS`].
+fn project_wgs84_corners(
+    min: &impl Coordinate,
+    max: &impl Coordinate,
+    method: ConversionMethod,
+) -> Result<(Point<f64>, Point<f64>), N3gbError> {
+    let min_bng = convert_to_bng(min, method)?;
+    let max_bng = convert_to_bng(max, method)?;
+
+    for (name, corner, bng) in [
+        ("min", (min.x(), min.y()), min_bng),
+        ("max", (max.x(), max.y()), max_bng),
+    ] {
+        check_in_grid_extents(&bng).map_err(|_| {
+            N3gbError::OutOfBounds(format!(
+                "{} corner ({}, {}) projects to ({:.1}, {:.1}), outside the BNG grid extents",
+                name,
+                corner.0,
+                corner.1,
+                bng.x(),
+                bng.y()
+            ))
+        })?;
+    }
+
+    Ok((min_bng, max_bng))
+}
+
+/// Checks that a BNG point lies within [`GRID_EXTENTS`].
+///
+/// # Arguments
+///
+/// * `point` - The point, in BNG (EPSG:27700) coordinates.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::OutOfBounds`] if the point is not finite or lies
+/// outside [`GRID_EXTENTS`].
+fn check_in_grid_extents(point: &Point<f64>) -> Result<(), N3gbError> {
+    let [min_x, min_y, max_x, max_y] = GRID_EXTENTS;
+    if (min_x..=max_x).contains(&point.x()) && (min_y..=max_y).contains(&point.y()) {
+        Ok(())
+    } else {
+        Err(N3gbError::OutOfBounds(format!(
+            "({}, {}) is outside the BNG grid extents",
+            point.x(),
+            point.y()
+        )))
+    }
+}
+
+/// Returns the inclusive `(min_row, max_row, min_col, max_col)` range covering a
+/// bounding box, from the grid addresses of its four corners.
+///
+/// The corners are first clamped to [`GRID_EXTENTS`], so a box reaching past
+/// the grid edge (e.g. the bounds of a coastal polygon) covers just its in-grid
+/// part.
+///
+/// # Errors
+///
+/// Returns `Err(InvalidZoomLevel)` if `zoom_level` exceeds `MAX_ZOOM_LEVEL`, or
+/// `Err(OutOfBounds)` if the box lies wholly outside `GRID_EXTENTS` or is not finite.
+fn extent_row_col_range(
     min_x: f64,
     min_y: f64,
     max_x: f64,
     max_y: f64,
     zoom_level: u8,
-) -> Result<Vec<HexCell>, N3gbError> {
+) -> Result<(i64, i64, i64, i64), N3gbError> {
+    let [grid_min_x, grid_min_y, grid_max_x, grid_max_y] = GRID_EXTENTS;
+    if !(max_x >= grid_min_x && min_x <= grid_max_x && max_y >= grid_min_y && min_y <= grid_max_y) {
+        return Err(N3gbError::OutOfBounds(format!(
+            "extent ({}, {}) - ({}, {}) lies outside the BNG grid extents",
+            min_x, min_y, max_x, max_y
+        )));
+    }
+    let (min_x, max_x) = (min_x.max(grid_min_x), max_x.min(grid_max_x));
+    let (min_y, max_y) = (min_y.max(grid_min_y), max_y.min(grid_max_y));
+
     let (ll_row, ll_col) = point_to_row_col(&(min_x, min_y), zoom_level)?;
     let (lr_row, lr_col) = point_to_row_col(&(max_x, min_y), zoom_level)?;
     let (ur_row, ur_col) = point_to_row_col(&(max_x, max_y), zoom_level)?;
@@ -1060,31 +2041,405 @@ fn generate_cells_for_extent(
     let min_col = ll_col.min(lr_col).min(ur_col).min(ul_col);
     let max_col = ll_col.max(lr_col).max(ur_col).max(ul_col);
 
-    let row_cols: Vec<(i64, i64)> = (min_row..=max_row)
-        .flat_map(|row| (min_col..=max_col).map(move |col| (row, col)))
+    Ok((min_row, max_row, min_col, max_col))
+}
+
+/// Generates all hex cells that cover a bounding box.
+///
+/// This is the single entry point for all grid construction. Every public
+/// constructor (`from_bng_extent`, `from_rect`, `from_bng_polygon`, etc.)
+/// ultimately calls this function.
+///
+/// ## How it works
+///
+/// 1. Converts the four corners of the bounding box to grid `(row, col)` addresses.
+/// 2. Takes the min/max of those to get the full row and column range.
+/// 3. Computes the northing of each row and the easting of each column (for
+///    even and odd rows) once, since every cell center in the range shares them.
+/// 4. Iterates the rows (in parallel via Rayon), pairing each row's northing
+///    with the column eastings to generate a `HexCell` per `(row, col)`.
+/// 5. Filters out any cells whose center falls outside the BNG grid extents.
+///
+/// The centers are bitwise equal to those from `row_col_to_center_unchecked`,
+/// which only depends on the row for the northing and on the column and row
+/// parity for the easting.
+///
+/// ## Errors
+///
+/// Returns `Err(InvalidZoomLevel)` if `zoom_level` exceeds `MAX_ZOOM_LEVEL`, or
+/// `Err(TooManyCells)` if the row/column range spans more than `MAX_GRID_CELLS`.
+fn generate_cells_for_extent(
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    zoom_level: u8,
+) -> Result<Vec<HexCell>, N3gbError> {
+    let (min_row, max_row, min_col, max_col) =
+        extent_row_col_range(min_x, min_y, max_x, max_y, zoom_level)?;
+    check_range_size(min_row, max_row, min_col, max_col)?;
+
+    let column_eastings = |parity: i64| -> Vec<f64> {
+        (min_col..=max_col)
+            .map(|col| row_col_to_center_unchecked(parity, col, zoom_level).x())
+            .collect()
+    };
+    let eastings = [column_eastings(0), column_eastings(1)];
+
+    let cells: Vec<HexCell> = (min_row..=max_row)
+        .into_par_iter()
+        .flat_map(|row| {
+            let y = row_col_to_center_unchecked(row, min_col, zoom_level).y();
+            if y < GRID_EXTENTS[1] {
+                return Vec::new();
+            }
+            (min_col..=max_col)
+                .zip(&eastings[row.rem_euclid(2) as usize])
+                .filter(|&(_, &x)| x >= GRID_EXTENTS[0])
+                .map(|(col, &x)| {
+                    let center = Point::new(x, y);
+                    let id = grid_cell_identifier(&center, zoom_level);
+                    HexCell::new(id, center, zoom_level, row, col)
+                })
+                .collect()
+        })
         .collect();
 
-    let cells: Vec<HexCell> = row_cols
+    Ok(cells)
+}
+
+/// Generates the hex cells covering a bounding box, plus any straddling its edges.
+///
+/// Starts from the same cells as [`generate_cells_for_extent`], then also
+/// scans a one-cell margin around the row/column range and keeps any cell
+/// whose hexagon intersects the bounding box. A cell reaching into the box has
+/// its centre within one cell of the corner range, so the margin is enough.
+///
+/// # Arguments
+///
+/// * `min_x` - Minimum easting of the bounding box.
+/// * `min_y` - Minimum northing of the bounding box.
+/// * `max_x` - Maximum easting of the bounding box.
+/// * `max_y` - Maximum northing of the bounding box.
+/// * `zoom_level` - The zoom level (0-15) for the cells.
+///
+/// # Returns
+///
+/// The covering cells in row-major order.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds
+/// `MAX_ZOOM_LEVEL`, or [`N3gbError::TooManyCells`] if the padded range spans
+/// more than `MAX_GRID_CELLS` addresses.
+fn generate_edge_cells_for_extent(
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    zoom_level: u8,
+) -> Result<Vec<HexCell>, N3gbError> {
+    let (min_row, max_row, min_col, max_col) =
+        extent_row_col_range(min_x, min_y, max_x, max_y, zoom_level)?;
+    let extent = Rect::new((min_x, min_y), (max_x, max_y));
+
+    let cells: Vec<HexCell> = extent_row_cols(min_x, min_y, max_x, max_y, zoom_level, 1)?
         .into_par_iter()
         .filter_map(|(row, col)| {
-            let center = row_col_to_center(row, col, zoom_level).ok()?;
+            let (center, id) = cell_center_and_id(row, col, zoom_level)?;
+            let cell = HexCell::new(id, center, zoom_level, row, col);
+            let in_range = (min_row..=max_row).contains(&row) && (min_col..=max_col).contains(&col);
+            (in_range || extent.intersects(&cell.to_polygon())).then_some(cell)
+        })
+        .collect();
+
+    Ok(cells)
+}
 
-            if center.x() < GRID_EXTENTS[0] || center.y() < GRID_EXTENTS[1] {
+/// Generates the IDs of all hex cells that cover a bounding box.
+///
+/// Uses the same traversal as grid construction (so the IDs match those of
+/// [`HexGrid::from_bng_extent`], in the same order) but skips building full
+/// [`HexCell`]s. Useful for cache warming and other ID-only workloads.
+///
+/// # Arguments
+///
+/// * `min_x` - Minimum easting of the bounding box.
+/// * `min_y` - Minimum northing of the bounding box.
+/// * `max_x` - Maximum easting of the bounding box.
+/// * `max_y` - Maximum northing of the bounding box.
+/// * `zoom_level` - The zoom level (0-15) for the cells.
+///
+/// # Returns
+///
+/// The IDs of the cells covering the bounding box.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds
+/// `MAX_ZOOM_LEVEL`, or [`N3gbError::TooManyCells`] if the extent would span
+/// more than `MAX_GRID_CELLS` cells.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::ids_for_extent;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let ids = ids_for_extent(457000.0, 339500.0, 458000.0, 340500.0, 10)?;
+/// assert!(!ids.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn ids_for_extent(
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    zoom_level: u8,
+) -> Result<Vec<String>, N3gbError> {
+    let ids: Vec<String> = extent_row_cols(min_x, min_y, max_x, max_y, zoom_level, 0)?
+        .into_par_iter()
+        .filter_map(|(row, col)| cell_center_and_id(row, col, zoom_level).map(|(_, id)| id))
+        .collect();
+
+    Ok(ids)
+}
+
+/// Computes the ID of the cell containing a BNG coordinate.
+///
+/// Equivalent to `HexCell::from_bng(coord, zoom_level)?.id`, but returns the
+/// ID alone without building a [`HexCell`]. Useful for hot indexing loops that
+/// only store IDs.
+///
+/// # Arguments
+///
+/// * `coord` - The BNG coordinate to locate.
+/// * `zoom_level` - The zoom level (0-15) for the cell.
+///
+/// # Returns
+///
+/// The ID of the cell containing `coord`.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds
+/// `MAX_ZOOM_LEVEL`, or [`N3gbError::OutOfBounds`] if `coord` lies outside the
+/// grid or its cell center cannot be encoded.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{HexCell, id_for_bng};
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let id = id_for_bng(&(383640.0, 398260.0), 10)?;
+/// assert_eq!(id, HexCell::from_bng(&(383640.0, 398260.0), 10)?.id);
+/// # Ok(())
+/// # }
+/// ```
+pub fn id_for_bng<C: Coordinate>(coord: &C, zoom_level: u8) -> Result<String, N3gbError> {
+    let (row, col) = point_to_row_col(coord, zoom_level)?;
+    let center = row_col_to_center_unchecked(row, col, zoom_level);
+    generate_hex_identifier(center.x(), center.y(), zoom_level)
+}
+
+/// Splits the BNG extent into square tiles and lazily yields the cells of each.
+///
+/// Tiles start at the south-west corner of [`GRID_EXTENTS`] and are yielded
+/// row by row, west to east, then south to north. Tiles on the northern and
+/// eastern edges are clipped to the extent, so together the tiles cover it
+/// exactly, without gaps or overlaps. Each cell belongs to the tile containing
+/// its center, counting a tile's southern and western edges as inside and its
+/// northern and eastern edges as outside, except on the extent's own edges.
+/// Every cell whose center lies within the extent therefore appears in
+/// exactly one tile. Cells are only generated when a tile is reached, which
+/// suits building tile pyramids without holding the national grid in memory.
+///
+/// # Arguments
+///
+/// * `zoom_level` - The zoom level (0-15) for the cells.
+/// * `tile_size_m` - The width and height of each tile, in meters.
+///
+/// # Returns
+///
+/// An iterator of `(tile, cells)` pairs, with each tile's cells in row-major
+/// order.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds
+/// `MAX_ZOOM_LEVEL`, [`N3gbError::InvalidDimension`] if `tile_size_m` is not
+/// a positive finite number, or [`N3gbError::TooManyCells`] if a single tile
+/// could hold more than `MAX_GRID_CELLS` cells.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::tiles;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// for (tile, cells) in tiles(3, 250_000.0)?.take(2) {
+///     println!("{:?}: {} cells", tile.min(), cells.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn tiles(
+    zoom_level: u8,
+    tile_size_m: f64,
+) -> Result<impl Iterator<Item = (Rect<f64>, Vec<HexCell>)>, N3gbError> {
+    if zoom_level > MAX_ZOOM_LEVEL {
+        return Err(N3gbError::InvalidZoomLevel(zoom_level));
+    }
+    if !tile_size_m.is_finite() || tile_size_m <= 0.0 {
+        return Err(N3gbError::InvalidDimension(format!(
+            "Tile size must be positive, got {}",
+            tile_size_m
+        )));
+    }
+
+    // The row/column range of a tile, padded by one cell on every side
+    let z = zoom_level as usize;
+    let cols = tile_size_m / CELL_WIDTHS[z] + 3.0;
+    let rows = tile_size_m / (1.5 * CELL_RADIUS[z]) + 3.0;
+    let count = (cols.ceil() * rows.ceil()).min(u64::MAX as f64) as u64;
+    if count > MAX_GRID_CELLS {
+        return Err(N3gbError::TooManyCells(count, MAX_GRID_CELLS));
+    }
+
+    let [min_x, min_y, max_x, max_y] = GRID_EXTENTS;
+    let tiles_x = ((max_x - min_x) / tile_size_m).ceil() as u64;
+    let tiles_y = ((max_y - min_y) / tile_size_m).ceil() as u64;
+
+    Ok((0..tiles_y).flat_map(move |ty| {
+        (0..tiles_x).map(move |tx| {
+            // Derive both edges from the tile index, so neighbouring tiles
+            // share an edge exactly and no cell falls between them
+            let x0 = min_x + tx as f64 * tile_size_m;
+            let y0 = min_y + ty as f64 * tile_size_m;
+            let x1 = (min_x + (tx + 1) as f64 * tile_size_m).min(max_x);
+            let y1 = (min_y + (ty + 1) as f64 * tile_size_m).min(max_y);
+            let tile = Rect::new((x0, y0), (x1, y1));
+            (tile, tile_cells(&tile, zoom_level))
+        })
+    }))
+}
+
+/// Generates the cells whose centers fall in a tile, for [`tiles`].
+///
+/// # Arguments
+///
+/// * `tile` - The tile, lying within [`GRID_EXTENTS`].
+/// * `zoom_level` - An already validated zoom level.
+///
+/// # Returns
+///
+/// The tile's cells in row-major order. A tile's northern and eastern edges
+/// are excluded unless they lie on the extent's edge.
+fn tile_cells(tile: &Rect<f64>, zoom_level: u8) -> Vec<HexCell> {
+    let (min, max) = (tile.min(), tile.max());
+    let (min_row, max_row, min_col, max_col) =
+        extent_row_col_range(min.x, min.y, max.x, max.y, zoom_level)
+            .expect("tile corners lie within the grid and the zoom level is valid");
+    let in_x = |x: f64| min.x <= x && (x < max.x || (max.x == GRID_EXTENTS[2] && x == max.x));
+    let in_y = |y: f64| min.y <= y && (y < max.y || (max.y == GRID_EXTENTS[3] && y == max.y));
+
+    (min_row - 1..=max_row + 1)
+        .flat_map(|row| (min_col - 1..=max_col + 1).map(move |col| (row, col)))
+        .filter_map(|(row, col)| {
+            let center = row_col_to_center_unchecked(row, col, zoom_level);
+            if !in_x(center.x()) || !in_y(center.y()) {
                 return None;
             }
-
-            let id = generate_hex_identifier(center.x(), center.y(), zoom_level);
+            let id = grid_cell_identifier(&center, zoom_level);
             Some(HexCell::new(id, center, zoom_level, row, col))
         })
-        .collect();
+        .collect()
+}
 
-    Ok(cells)
+/// Lists every `(row, col)` address in the row/column range covering a bounding box.
+///
+/// # Arguments
+///
+/// * `min_x` - Minimum easting of the bounding box.
+/// * `min_y` - Minimum northing of the bounding box.
+/// * `max_x` - Maximum easting of the bounding box.
+/// * `max_y` - Maximum northing of the bounding box.
+/// * `zoom_level` - The zoom level (0-15) for the cells.
+/// * `padding` - Extra rows and columns to add on every side of the range.
+///
+/// # Returns
+///
+/// The `(row, col)` pairs in row-major order.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` exceeds
+/// `MAX_ZOOM_LEVEL`, or [`N3gbError::TooManyCells`] if the range spans more
+/// than `MAX_GRID_CELLS` addresses.
+fn extent_row_cols(
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    zoom_level: u8,
+    padding: i64,
+) -> Result<Vec<(i64, i64)>, N3gbError> {
+    let (min_row, max_row, min_col, max_col) =
+        extent_row_col_range(min_x, min_y, max_x, max_y, zoom_level)?;
+    let (min_row, max_row) = (min_row - padding, max_row + padding);
+    let (min_col, max_col) = (min_col - padding, max_col + padding);
+    check_range_size(min_row, max_row, min_col, max_col)?;
+
+    Ok((min_row..=max_row)
+        .flat_map(|row| (min_col..=max_col).map(move |col| (row, col)))
+        .collect())
+}
+
+/// Checks that an inclusive row/column range holds at most `MAX_GRID_CELLS` addresses.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::TooManyCells`] if the range is too large.
+fn check_range_size(
+    min_row: i64,
+    max_row: i64,
+    min_col: i64,
+    max_col: i64,
+) -> Result<(), N3gbError> {
+    let count = (max_row - min_row + 1) as u64 * (max_col - min_col + 1) as u64;
+    if count > MAX_GRID_CELLS {
+        return Err(N3gbError::TooManyCells(count, MAX_GRID_CELLS));
+    }
+    Ok(())
+}
+
+/// Computes the center and ID of a cell, skipping cells outside the BNG grid.
+///
+/// # Arguments
+///
+/// * `row` - The row index of the cell.
+/// * `col` - The column index of the cell.
+/// * `zoom_level` - An already validated zoom level.
+///
+/// # Returns
+///
+/// The cell's center and ID, or `None` if the center falls below the grid's
+/// minimum easting or northing.
+fn cell_center_and_id(row: i64, col: i64, zoom_level: u8) -> Option<(Point<f64>, String)> {
+    let center = row_col_to_center_unchecked(row, col, zoom_level);
+
+    if center.x() < GRID_EXTENTS[0] || center.y() < GRID_EXTENTS[1] {
+        return None;
+    }
+
+    let id = grid_cell_identifier(&center, zoom_level);
+    Some((center, id))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use geo_types::{coord, point};
+    use crate::index::row_col_to_center;
+    use geo_types::{LineString, MultiLineString, coord, point};
 
     #[test]
     fn test_hex_grid_from_bng_extent() -> Result<(), N3gbError> {
@@ -1098,6 +2453,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hex_grid_national() -> Result<(), N3gbError> {
+        let grid = HexGrid::national(4)?;
+        assert!(grid.len() > 500);
+        assert!((grid.len() as u64) < MAX_GRID_CELLS);
+
+        assert!(matches!(
+            HexGrid::national(12),
+            Err(N3gbError::TooManyCells(_, MAX_GRID_CELLS))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_matches_sequential_iteration() -> Result<(), N3gbError> {
+        // `from_bng_polygon` runs on rayon when `parallel` is enabled, while
+        // `iter_bng_polygon_cells` is always a plain iterator. CI also runs the
+        // suite with `parallel` disabled, covering the sequential shim.
+        let triangle = Polygon::new(
+            LineString::from(vec![
+                (457000.0, 339500.0),
+                (458000.0, 339500.0),
+                (457500.0, 340500.0),
+                (457000.0, 339500.0),
+            ]),
+            vec![],
+        );
+        let grid = HexGrid::from_bng_polygon(&triangle, 10)?;
+        let expected: Vec<HexCell> = HexGrid::iter_bng_polygon_cells(&triangle, 10)?.collect();
+
+        assert!(!expected.is_empty());
+        assert_eq!(grid.cells(), expected.as_slice());
+        assert_eq!(
+            grid.to_polygons(),
+            expected.iter().map(|c| c.to_polygon()).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_hex_grid_from_rect() -> Result<(), N3gbError> {
         let rect = Rect::new(
@@ -1152,6 +2546,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_par_filter_matches_filter() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+
+        let predicate = |cell: &HexCell| cell.easting() > 457500.0;
+        let expected: HashSet<&str> = grid
+            .filter(predicate)
+            .iter()
+            .map(|c| c.id.as_str())
+            .collect();
+        let filtered = grid.par_filter(predicate);
+        let actual: HashSet<&str> = filtered.iter().map(|c| c.id.as_str()).collect();
+
+        assert!(!actual.is_empty());
+        assert_eq!(actual, expected);
+        assert_eq!(filtered.zoom_level(), grid.zoom_level());
+        for cell in filtered.iter() {
+            assert_eq!(filtered.get_cell_at(&cell.center), Some(cell));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_bng_polygon_cells_matches_from_bng_polygon() -> Result<(), N3gbError> {
+        let polygon = Polygon::new(
+            geo_types::LineString::from(vec![
+                coord! { x: 457000.0, y: 339500.0 },
+                coord! { x: 458200.0, y: 339800.0 },
+                coord! { x: 457600.0, y: 340600.0 },
+                coord! { x: 457000.0, y: 339500.0 },
+            ]),
+            vec![],
+        );
+
+        let grid = HexGrid::from_bng_polygon(&polygon, 11)?;
+        let expected: HashSet<String> = grid.iter().map(|c| c.id.clone()).collect();
+        let actual: HashSet<String> = HexGrid::iter_bng_polygon_cells(&polygon, 11)?
+            .map(|c| c.id)
+            .collect();
+
+        assert!(!actual.is_empty());
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_convex_hull() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let hull = grid.convex_hull().expect("grid has at least 3 cells");
+
+        for cell in grid.iter() {
+            assert!(hull.intersects(&cell.center));
+        }
+
+        let small = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(457000.0, 339500.0), 10)?;
+        assert!(small.len() < 3);
+        assert!(small.convex_hull().is_none());
+        Ok(())
+    }
+
     #[test]
     fn test_to_polygons() -> Result<(), N3gbError> {
         let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
@@ -1161,6 +2615,791 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_boundary_cells() -> Result<(), N3gbError> {
+        use geo_types::polygon;
+
+        let grid = HexGrid::from_bng_extent(&(456500.0, 339000.0), &(458500.0, 341000.0), 10)?;
+        let square = polygon![
+            (x: 457000.0, y: 339500.0),
+            (x: 458000.0, y: 339500.0),
+            (x: 458000.0, y: 340500.0),
+            (x: 457000.0, y: 340500.0),
+            (x: 457000.0, y: 339500.0),
+        ];
+
+        let boundary = grid.boundary_cells(&square);
+        assert!(!boundary.is_empty());
+
+        for cell in &boundary {
+            assert!(square.exterior().intersects(&cell.to_polygon()));
+        }
+
+        let interior = grid.filter(|cell| square.contains(&cell.to_polygon()));
+        assert!(!interior.is_empty());
+        for cell in interior {
+            assert!(!boundary.iter().any(|b| b.id == cell.id));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cells_intersecting_buffer() -> Result<(), N3gbError> {
+        use crate::index::CELL_RADIUS;
+        use geo::{Distance, Euclidean};
+
+        let zoom = 10;
+        let grid = HexGrid::from_bng_extent(&(456500.0, 339000.0), &(458500.0, 341000.0), zoom)?;
+        let center = grid
+            .get_cell_at(&Point::new(457500.0, 340000.0))
+            .unwrap()
+            .center;
+        let radius = CELL_RADIUS[zoom as usize];
+
+        // Centre distance only finds the cell itself; the buffer reaches the
+        // hexagons of all six neighbours, whose edges are closer than the radius
+        let by_center = grid.filter(|cell| Euclidean.distance(&cell.center, &center) <= radius);
+        assert_eq!(by_center.len(), 1);
+
+        let touched = grid.cells_intersecting_buffer(&center, radius);
+        assert_eq!(touched.len(), 7);
+        for cell in &by_center {
+            assert!(touched.iter().any(|t| t.id == cell.id));
+        }
+
+        assert!(grid.cells_intersecting_buffer(&center, -1.0).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_geojson_with_properties() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let keys = |geojson: GeoJson| -> Vec<Vec<String>> {
+            let GeoJson::FeatureCollection(fc) = geojson else {
+                panic!("expected a FeatureCollection");
+            };
+            fc.features
+                .into_iter()
+                .map(|f| {
+                    let mut keys: Vec<String> =
+                        f.properties.unwrap_or_default().keys().cloned().collect();
+                    keys.sort();
+                    keys
+                })
+                .collect()
+        };
+
+        let restricted = keys(grid.to_geojson_with(&["id", "easting"])?);
+        assert_eq!(restricted.len(), grid.len());
+        for feature_keys in &restricted {
+            assert_eq!(feature_keys, &["easting", "id"]);
+        }
+
+        let all = keys(grid.to_geojson_with(&[])?);
+        for feature_keys in &all {
+            assert_eq!(
+                feature_keys,
+                &["col", "easting", "id", "northing", "row", "zoom_level"]
+            );
+        }
+
+        assert!(matches!(
+            grid.to_geojson_with(&["colour"]),
+            Err(N3gbError::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tiles_cover_extent() -> Result<(), N3gbError> {
+        let [min_x, min_y, max_x, max_y] = GRID_EXTENTS;
+        let zoom = 5;
+        let all: Vec<(Rect<f64>, Vec<HexCell>)> = tiles(zoom, 200_000.0)?.collect();
+        assert_eq!(all.len(), 4 * 7);
+
+        // The tiles partition the extent: their areas add up to it and
+        // neighbouring tiles share edges
+        let area: f64 = all.iter().map(|(tile, _)| tile.unsigned_area()).sum();
+        assert!((area - (max_x - min_x) * (max_y - min_y)).abs() < 1e-3);
+        for pair in all.windows(2) {
+            let (a, b) = (&pair[0].0, &pair[1].0);
+            if a.min().y == b.min().y {
+                assert_eq!(a.max().x, b.min().x);
+            } else {
+                assert_eq!(b.min().x, min_x);
+                assert_eq!(a.max().x, max_x);
+            }
+        }
+
+        // Every cell centered in the extent appears in exactly one tile
+        let mut seen: HashSet<String> = HashSet::new();
+        for (tile, cells) in &all {
+            for cell in cells {
+                assert!((tile.min().x..=tile.max().x).contains(&cell.easting()));
+                assert!((tile.min().y..=tile.max().y).contains(&cell.northing()));
+                assert!(seen.insert(cell.id.clone()));
+            }
+        }
+        let national = HexGrid::national(zoom)?;
+        let centered: Vec<&HexCell> = national.filter(|cell| {
+            (min_x..=max_x).contains(&cell.easting()) && (min_y..=max_y).contains(&cell.northing())
+        });
+        assert_eq!(seen.len(), centered.len());
+        for cell in centered {
+            assert!(seen.contains(&cell.id));
+        }
+
+        // Edges of a size that is not exact in floating point still line up
+        let size = 111_111.1;
+        let per_row = ((max_x - min_x) / size).ceil() as usize;
+        let odd: Vec<Rect<f64>> = tiles(zoom, size)?.map(|(tile, _)| tile).collect();
+        for (i, tile) in odd.iter().enumerate() {
+            if let Some(east) = odd.get(i + 1).filter(|_| (i + 1) % per_row != 0) {
+                assert_eq!(tile.max().x, east.min().x);
+            }
+            if let Some(north) = odd.get(i + per_row) {
+                assert_eq!(tile.max().y, north.min().y);
+            }
+        }
+
+        assert!(matches!(
+            tiles(zoom, 0.0),
+            Err(N3gbError::InvalidDimension(_))
+        ));
+        assert!(matches!(
+            tiles(16, 1000.0),
+            Err(N3gbError::InvalidZoomLevel(16))
+        ));
+        assert!(matches!(
+            tiles(15, 100_000.0),
+            Err(N3gbError::TooManyCells(_, _))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_adjacency_block() -> Result<(), N3gbError> {
+        let zoom = 10;
+        let (row, col) = (3000, 3500);
+        let cells = [
+            (row, col),
+            (row, col + 1),
+            (row + 1, col),
+            (row + 1, col + 1),
+        ]
+        .into_iter()
+        .map(|(r, c)| {
+            let center = row_col_to_center(r, c, zoom)?;
+            let id = generate_hex_identifier(center.x(), center.y(), zoom)?;
+            Ok(HexCell::new(id, center, zoom, r, c))
+        })
+        .collect::<Result<Vec<_>, N3gbError>>()?;
+        let grid = HexGrid::new(cells, zoom);
+
+        let edges = grid.adjacency();
+        // Two horizontal pairs plus three diagonals between the rows
+        assert_eq!(edges.len(), 5);
+        for (a, b) in edges {
+            assert!(a < b);
+            assert_eq!(grid.cells()[a].grid_distance(&grid.cells()[b])?, 1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_disaggregate() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(459000.0, 341500.0), 10)?;
+        let poly = Polygon::new(
+            geo_types::LineString::from(vec![
+                (457600.0, 340000.0),
+                (458400.0, 340100.0),
+                (458200.0, 340900.0),
+                (457500.0, 340700.0),
+                (457600.0, 340000.0),
+            ]),
+            vec![],
+        );
+
+        let shares = grid.disaggregate(&poly, 1250.0);
+        assert!(shares.len() > 1);
+        assert!(shares.iter().all(|(_, share)| *share > 0.0));
+
+        let total: f64 = shares.iter().map(|(_, share)| share).sum();
+        assert!((total - 1250.0).abs() < 1e-6);
+
+        // A cell wholly inside the polygon gets the full-hexagon share
+        let full_area = grid.cells()[0].to_polygon().unsigned_area();
+        let max_share = shares.iter().map(|(_, s)| *s).fold(0.0, f64::max);
+        assert!((max_share - 1250.0 * full_area / poly.unsigned_area()).abs() < 1e-4);
+
+        let far = Polygon::new(
+            geo_types::LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 0.0)]),
+            vec![],
+        );
+        assert!(grid.disaggregate(&far, 1250.0).is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_iter() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+
+        assert_eq!(grid.par_iter().count(), grid.len());
+        let max_row = grid.par_iter().map(|cell| cell.row).max();
+        assert_eq!(max_row, grid.iter().map(|cell| cell.row).max());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_geojson_simplified() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let width = crate::index::CELL_WIDTHS[10];
+
+        let geometry_types = |geojson: GeoJson| {
+            let GeoJson::FeatureCollection(fc) = geojson else {
+                panic!("expected a FeatureCollection");
+            };
+            assert_eq!(fc.features.len(), grid.len());
+            fc.features
+                .into_iter()
+                .map(|f| f.geometry.unwrap().value.type_name())
+                .collect::<HashSet<_>>()
+        };
+
+        // Each cell spans 2 px at this scale: below a 4 px threshold, above a 1 px one.
+        let map_scale = width / 2.0;
+        assert_eq!(
+            geometry_types(grid.to_geojson_simplified(4.0, map_scale)?),
+            HashSet::from(["Point"])
+        );
+        assert_eq!(
+            geometry_types(grid.to_geojson_simplified(1.0, map_scale)?),
+            HashSet::from(["Polygon"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_wgs84_extent_outside_gb() -> Result<(), N3gbError> {
+        let brittany = (-4.5, 48.4);
+        let manchester = (-2.2, 53.5);
+
+        // OSTN15 has no data for France and fails to project; PROJ projects the
+        // corner south of the false origin, which the extent check rejects.
+        for method in [ConversionMethod::Ostn15, ConversionMethod::Proj] {
+            let result = HexGrid::from_wgs84_extent(&brittany, &manchester, 10, method);
+            assert!(matches!(
+                result,
+                Err(N3gbError::OutOfBounds(_)) | Err(N3gbError::ProjectionError(_))
+            ));
+            let result = HexGrid::builder()
+                .conversion_method(method)
+                .wgs84_extent(&brittany, &manchester);
+            assert!(result.is_err());
+        }
+
+        assert!(check_in_grid_extents(&Point::new(457500.0, 340000.0)).is_ok());
+        for outside in [(150000.0, -120000.0), (800000.0, 340000.0), (f64::NAN, 0.0)] {
+            assert!(matches!(
+                check_in_grid_extents(&Point::new(outside.0, outside.1)),
+                Err(N3gbError::OutOfBounds(_))
+            ));
+        }
+
+        assert!(
+            HexGrid::from_wgs84_extent(&(-2.3, 53.4), &manchester, 10, ConversionMethod::Ostn15)
+                .is_ok()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_filter() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let predicate = |cell: &HexCell| cell.easting() > 457500.0;
+
+        let lazy: Vec<&HexCell> = grid.iter_filter(predicate).collect();
+        assert!(!lazy.is_empty());
+        assert_eq!(lazy, grid.filter(predicate));
+        assert_eq!(
+            grid.iter_filter(predicate).map(|cell| cell.row).max(),
+            lazy.iter().map(|cell| cell.row).max()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_geojson_str() -> Result<(), N3gbError> {
+        let feature = r#"{
+            "type": "Feature",
+            "properties": {"name": "patch"},
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [[[-2.25, 53.47], [-2.23, 53.47], [-2.23, 53.49], [-2.25, 53.49], [-2.25, 53.47]]]
+            }
+        }"#;
+        let grid = HexGrid::from_geojson_str(feature, 10)?;
+        assert!(!grid.is_empty());
+        assert_eq!(grid.zoom_level(), 10);
+
+        let multi = r#"{"type": "MultiPolygon", "coordinates": [[[[-2.25, 53.47], [-2.23, 53.47], [-2.23, 53.49], [-2.25, 53.49], [-2.25, 53.47]]]]}"#;
+        assert_eq!(HexGrid::from_geojson_str(multi, 10)?.len(), grid.len());
+
+        let point = r#"{"type": "Point", "coordinates": [-2.24, 53.48]}"#;
+        assert!(matches!(
+            HexGrid::from_geojson_str(point, 10),
+            Err(N3gbError::GeometryParseError(_))
+        ));
+        assert!(HexGrid::from_geojson_str("not json", 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_include_edge_cells() -> Result<(), N3gbError> {
+        let (min, max) = ((457010.0, 339010.0), (458990.0, 340990.0));
+        let extent = Rect::new(min, max);
+        let build = |include| {
+            HexGridBuilder::new()
+                .zoom_level(10)
+                .bng_extent(&min, &max)
+                .include_edge_cells(include)
+                .build()
+        };
+
+        let ragged = build(false)?;
+        let full = build(true)?;
+        assert!(full.len() > ragged.len());
+
+        // Every default cell is kept, and every added cell reaches into the extent
+        let ragged_ids: HashSet<&str> = ragged.iter().map(|c| c.id.as_str()).collect();
+        let full_ids: HashSet<&str> = full.iter().map(|c| c.id.as_str()).collect();
+        assert!(ragged_ids.is_subset(&full_ids));
+        for cell in full.iter().filter(|c| !ragged_ids.contains(c.id.as_str())) {
+            assert!(extent.intersects(&cell.to_polygon()));
+        }
+
+        // No cell touching the extent is left out
+        let padded = HexGrid::from_bng_extent(&(456000.0, 338000.0), &(460000.0, 342000.0), 10)?;
+        for cell in padded.iter() {
+            if extent.intersects(&cell.to_polygon()) {
+                assert!(full_ids.contains(cell.id.as_str()));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_guarantee_nonempty() -> Result<(), N3gbError> {
+        let build = |poly: Polygon<f64>, guarantee| {
+            HexGrid::builder()
+                .zoom_level(5)
+                .bng_polygon(poly)
+                .guarantee_nonempty(guarantee)
+                .build()
+        };
+
+        // A 1m square in the corner of a cell's rectangle but outside its
+        // hexagon lies in a neighbouring hexagon, which is still found
+        let home = HexCell::from_bng(&(457500.0, 340000.0), 5)?;
+        let radius = CELL_RADIUS[5];
+        let (x, y) = (
+            home.center.x() + 0.8 * radius,
+            home.center.y() + 0.7 * radius,
+        );
+        let tiny = Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + 1.0, y),
+                (x + 1.0, y + 1.0),
+                (x, y + 1.0),
+                (x, y),
+            ]),
+            vec![],
+        );
+        let grid = build(tiny.clone(), false)?;
+        assert_eq!(grid.len(), 1);
+        assert_ne!(grid.cells()[0], home);
+        assert!(grid.cells()[0].to_polygon().intersects(&tiny));
+        assert_eq!(build(tiny, true)?.cells(), grid.cells());
+
+        // A ring collapsed to a single point still selects the cell around it
+        let collapsed = Polygon::new(LineString::from(vec![(x, y); 4]), vec![]);
+        let grid = build(collapsed.clone(), false)?;
+        assert_eq!(grid.len(), 1);
+        assert!(grid.cells()[0].to_polygon().intersects(&collapsed));
+
+        // A polygon with no vertices has no centroid, so stays empty either way
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        assert!(build(empty.clone(), true)?.is_empty());
+        assert!(build(empty, false)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_coverage_mode() -> Result<(), N3gbError> {
+        let triangle = Polygon::new(
+            geo_types::LineString::from(vec![
+                (457000.0, 339500.0),
+                (458000.0, 339500.0),
+                (457000.0, 340500.0),
+                (457000.0, 339500.0),
+            ]),
+            vec![],
+        );
+        let build = |mode| {
+            HexGrid::builder()
+                .zoom_level(10)
+                .bng_polygon(triangle.clone())
+                .coverage(mode)
+                .build()
+        };
+
+        let exact = build(CoverageMode::Exact)?;
+        let bbox = build(CoverageMode::BoundingBox)?;
+        assert!(bbox.len() >= exact.len());
+        assert!(
+            exact
+                .iter()
+                .all(|cell| bbox.get_cell_at(&cell.center).is_some())
+        );
+        assert_eq!(exact.len(), HexGrid::from_bng_polygon(&triangle, 10)?.len());
+        assert_eq!(
+            bbox.len(),
+            HexGrid::from_rect_with_edges(&triangle.bounding_rect().unwrap(), 10)?.len()
+        );
+
+        let multi = HexGrid::builder()
+            .zoom_level(10)
+            .bng_multipolygon(MultiPolygon::new(vec![triangle.clone()]))
+            .coverage(CoverageMode::BoundingBox)
+            .build()?;
+        assert_eq!(multi.len(), bbox.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_to_bng_polygon() -> Result<(), N3gbError> {
+        let triangle = Polygon::new(
+            geo_types::LineString::from(vec![
+                (457000.0, 339500.0),
+                (458000.0, 339500.0),
+                (457000.0, 340500.0),
+                (457000.0, 339500.0),
+            ]),
+            vec![],
+        );
+        let grid = HexGrid::builder()
+            .zoom_level(10)
+            .rect(&triangle.bounding_rect().unwrap())
+            .include_edge_cells(true)
+            .build()?;
+
+        let clipped = grid.clip_to_bng_polygon(&triangle);
+        assert!(clipped.len() < grid.len());
+        assert_eq!(clipped.zoom_level(), grid.zoom_level());
+
+        let expected = HexGrid::from_bng_polygon(&triangle, 10)?;
+        let ids = |g: &HexGrid| g.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&clipped), ids(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+
+        let sample = grid.sample(10, 42);
+        assert_eq!(sample.len(), 10.min(grid.len()));
+        assert_eq!(sample.zoom_level(), grid.zoom_level());
+
+        let ids = |g: &HexGrid| g.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&sample), ids(&grid.sample(10, 42)));
+        assert_ne!(ids(&sample), ids(&grid.sample(10, 7)));
+        assert!(sample.iter().all(|c| grid.get_cell_at(&c.center).is_some()));
+
+        assert_eq!(grid.sample(grid.len() + 5, 42).len(), grid.len());
+        assert!(grid.sample(0, 42).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bng_geometry_dedups() -> Result<(), N3gbError> {
+        let out = LineString::from(vec![(383000.0, 398000.0), (386000.0, 398000.0)]);
+        let back = LineString::from(vec![(386000.0, 398000.0), (383000.0, 398000.0)]);
+        let across = LineString::from(vec![(384500.0, 397000.0), (384500.0, 399000.0)]);
+        let geom = Geometry::MultiLineString(MultiLineString::new(vec![out.clone(), back, across]));
+
+        let raw = HexCell::from_geometry(geom.clone(), 10, Crs::Bng, ConversionMethod::default())?;
+        let grid = HexGrid::from_bng_geometry(geom, 10)?;
+
+        let unique: HashSet<&str> = raw.iter().map(|c| c.id.as_str()).collect();
+        assert!(raw.len() > grid.len());
+        assert_eq!(grid.len(), unique.len());
+        assert_eq!(grid.zoom_level(), 10);
+        // The first line contributes its cells in order; the reversed line adds none
+        let out_cells = HexCell::from_line_string_bng(&out, 10)?;
+        assert_eq!(grid.cells()[..out_cells.len()], out_cells[..]);
+        assert!(grid.iter().all(|c| grid.get_cell_at(&c.center) == Some(c)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_wgs84_geometry() -> Result<(), N3gbError> {
+        let geom = Geometry::Point(Point::new(-2.248423716278411, 53.4804537960769));
+        let grid = HexGrid::from_wgs84_geometry(geom, 10, ConversionMethod::default())?;
+        assert_eq!(grid.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_id_for_bng() -> Result<(), N3gbError> {
+        for zoom_level in [0, 6, 10, 15] {
+            for coord in [
+                (383640.0, 398260.0),
+                (457500.0, 340000.0),
+                (252086.1, 847702.9),
+            ] {
+                assert_eq!(
+                    id_for_bng(&coord, zoom_level)?,
+                    HexCell::from_bng(&coord, zoom_level)?.id
+                );
+            }
+        }
+        assert!(matches!(
+            id_for_bng(&(383640.0, 398260.0), 16),
+            Err(N3gbError::InvalidZoomLevel(16))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cells_with_polygons() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let pairs = grid.cells_with_polygons();
+
+        assert_eq!(pairs.len(), grid.len());
+        for ((cell, polygon), (expected_cell, expected_polygon)) in
+            pairs.iter().zip(grid.iter().zip(grid.to_polygons()))
+        {
+            assert_eq!(*cell, expected_cell);
+            assert_eq!(*polygon, expected_polygon);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_center_bng() -> Result<(), N3gbError> {
+        let coord = (383640.0, 398260.0);
+        let center = HexCell::from_bng(&coord, 10)?;
+
+        let single = HexGrid::from_center_bng(&coord, 10, 0)?;
+        assert_eq!(single.cells(), std::slice::from_ref(&center));
+
+        let mut previous = single.len();
+        for k in 1..=4u32 {
+            let disk = HexGrid::from_center_bng(&coord, 10, k)?;
+            assert_eq!(disk.len() as u32, 3 * k * (k + 1) + 1);
+            assert!(disk.len() > previous);
+            previous = disk.len();
+
+            for cell in disk.iter() {
+                assert!(center.grid_distance(cell)? <= k as u64);
+                assert!(disk.get_cell_at(&cell.center) == Some(cell));
+            }
+            let ring: Vec<_> = disk
+                .iter()
+                .filter(|c| center.grid_distance(c).unwrap() == k as u64)
+                .collect();
+            assert_eq!(ring.len() as u32, 6 * k);
+        }
+
+        assert!(matches!(
+            HexGrid::from_center_bng(&coord, 10, u32::MAX),
+            Err(N3gbError::TooManyCells(_, _))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_by() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let parts = grid.partition_by(|cell| cell.row.rem_euclid(2));
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts.values().map(HexGrid::len).sum::<usize>(), grid.len());
+        for (parity, part) in &parts {
+            assert_eq!(part.zoom_level(), grid.zoom_level());
+            assert!(part.iter().all(|c| c.row.rem_euclid(2) == *parity));
+            assert!(part.iter().all(|c| part.get_cell_at(&c.center) == Some(c)));
+        }
+
+        let empty = grid.par_filter(|_| false);
+        assert!(empty.partition_by(|c| c.row).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ids_for_extent() -> Result<(), N3gbError> {
+        let (min, max) = ((457000.0, 339500.0), (458000.0, 340500.0));
+        let grid = HexGrid::from_bng_extent(&min, &max, 10)?;
+        let ids = ids_for_extent(min.0, min.1, max.0, max.1, 10)?;
+
+        assert_eq!(ids.len(), grid.len());
+        assert!(ids.iter().zip(grid.iter()).all(|(id, cell)| *id == cell.id));
+        assert!(matches!(
+            ids_for_extent(min.0, min.1, max.0, max.1, 16),
+            Err(N3gbError::InvalidZoomLevel(16))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbor_map() -> Result<(), N3gbError> {
+        let zoom = 10;
+        let (row, col) = (3000, 3500);
+        let cells = (row..row + 3)
+            .flat_map(|r| (col..col + 3).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                let center = row_col_to_center(r, c, zoom)?;
+                let id = generate_hex_identifier(center.x(), center.y(), zoom)?;
+                Ok(HexCell::new(id, center, zoom, r, c))
+            })
+            .collect::<Result<Vec<_>, N3gbError>>()?;
+        let grid = HexGrid::new(cells, zoom);
+
+        let map = grid.neighbor_map();
+        assert_eq!(map.len(), 9);
+
+        let corner = &grid.cells()[0];
+        let interior = &grid.cells()[4];
+        assert_eq!((interior.row, interior.col), (row + 1, col + 1));
+        assert_eq!(map[&interior.id].len(), 6);
+        assert!(map[&corner.id].len() < map[&interior.id].len());
+
+        let ids: HashSet<&String> = map.keys().collect();
+        for neighbors in map.values() {
+            assert!(neighbors.iter().all(|id| ids.contains(id)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_extent_fast_path_matches_per_cell_centers() -> Result<(), N3gbError> {
+        let extents = [
+            (457000.0, 339500.0, 458000.0, 340500.0, 10),
+            (457000.25, 339500.75, 457000.5, 339501.0, 15),
+            (0.0, 0.0, 20000.0, 15000.0, 8),
+            (100.0, 100.0, 5000.0, 5000.0, 9),
+            (700000.0, 1300000.0, 750000.0, 1350000.0, 7),
+            (0.0, 0.0, 750000.0, 1350000.0, 3),
+        ];
+        for (min_x, min_y, max_x, max_y, zoom) in extents {
+            let expected: Vec<HexCell> = extent_row_cols(min_x, min_y, max_x, max_y, zoom, 0)?
+                .into_iter()
+                .filter_map(|(row, col)| {
+                    let (center, id) = cell_center_and_id(row, col, zoom)?;
+                    Some(HexCell::new(id, center, zoom, row, col))
+                })
+                .collect();
+            let cells = generate_cells_for_extent(min_x, min_y, max_x, max_y, zoom)?;
+            assert!(!cells.is_empty());
+            assert_eq!(cells, expected, "extent {:?}", (min_x, min_y, max_x, max_y));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_boundary() -> Result<(), N3gbError> {
+        let west = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let east = HexGrid::from_bng_extent(&(457800.0, 339500.0), &(459000.0, 340500.0), 10)?;
+
+        let seam = west.shared_boundary(&east)?;
+        let seam_ids: HashSet<&str> = seam.iter().map(|cell| cell.id.as_str()).collect();
+        assert_eq!(seam_ids.len(), seam.len());
+
+        // Every overlapping cell is on the seam
+        let overlap: Vec<&HexCell> = west
+            .iter()
+            .filter(|cell| east.index.contains_key(&(cell.row, cell.col)))
+            .collect();
+        assert!(!overlap.is_empty());
+        assert!(
+            overlap
+                .iter()
+                .all(|cell| seam_ids.contains(cell.id.as_str()))
+        );
+
+        // The seam is a strip around the overlap, not either whole grid
+        let (min_x, max_x) = seam.iter().fold((f64::MAX, f64::MIN), |(lo, hi), cell| {
+            (lo.min(cell.easting()), hi.max(cell.easting()))
+        });
+        assert!(min_x > 457500.0 && max_x < 458300.0);
+        for cell in &seam {
+            let in_west = west.index.contains_key(&(cell.row, cell.col));
+            let in_east = east.index.contains_key(&(cell.row, cell.col));
+            assert!(in_west || in_east);
+            let other = if in_west { &east } else { &west };
+            assert!(
+                other.index.contains_key(&(cell.row, cell.col))
+                    || neighbor_row_cols(cell.row, cell.col)
+                        .iter()
+                        .any(|n| other.index.contains_key(n))
+            );
+        }
+
+        // The seam is the same set of cells from either side
+        let reverse: HashSet<String> = east
+            .shared_boundary(&west)?
+            .into_iter()
+            .map(|cell| cell.id)
+            .collect();
+        assert_eq!(
+            reverse,
+            seam_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<HashSet<_>>()
+        );
+
+        let far = HexGrid::from_bng_extent(&(470000.0, 339500.0), &(471000.0, 340500.0), 10)?;
+        assert!(west.shared_boundary(&far)?.is_empty());
+
+        let other_zoom =
+            HexGrid::from_bng_extent(&(457800.0, 339500.0), &(459000.0, 340500.0), 11)?;
+        assert_eq!(
+            west.shared_boundary(&other_zoom).err(),
+            Some(N3gbError::ZoomLevelMismatch(10, 11))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_extent_straddling_grid_origin() -> Result<(), N3gbError> {
+        use geo_types::polygon;
+
+        let straddling = HexGrid::from_bng_extent(&(-5000.0, -5000.0), &(5000.0, 5000.0), 10)?;
+        let inside = HexGrid::from_bng_extent(&(0.0, 0.0), &(5000.0, 5000.0), 10)?;
+        assert!(!straddling.is_empty());
+        assert_eq!(straddling.cells(), inside.cells());
+
+        let coastal = polygon![
+            (x: -3000.0, y: 1000.0),
+            (x: 4000.0, y: 1000.0),
+            (x: 4000.0, y: 6000.0),
+            (x: -3000.0, y: 1000.0),
+        ];
+        let grid = HexGrid::from_bng_polygon(&coastal, 10)?;
+        assert!(!grid.is_empty());
+        assert!(grid.iter().all(|cell| cell.easting() >= 0.0));
+
+        assert!(matches!(
+            HexGrid::from_bng_extent(&(-9000.0, 1000.0), &(-5000.0, 5000.0), 10),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_zoom_level() {
         let result = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 20);
@@ -0,0 +1,95 @@
+use crate::cell::HexCell;
+use crate::coord::{convert_to_wgs84, preferred_method};
+use crate::error::N3gbError;
+use h3o::{CellIndex, LatLng, Resolution};
+
+impl HexCell {
+    /// Returns the H3 index containing this cell's center.
+    ///
+    /// The center is reprojected from BNG to WGS84 and indexed at the given H3
+    /// resolution. Requires the `h3` feature.
+    ///
+    /// # Arguments
+    /// * `resolution` - The H3 resolution (0-15) to index at.
+    ///
+    /// # Returns
+    /// The 64-bit H3 cell index containing the cell center.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::H3Error`] if `resolution` is not a valid H3 resolution
+    /// or the reprojected center is not a valid coordinate, and
+    /// [`N3gbError::ProjectionError`] if the center cannot be converted to WGS84.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::HexCell;
+    ///
+    /// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+    /// let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+    /// let h3_index = cell.to_h3(9)?;
+    /// assert!(cell.is_within_h3(h3_index)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_h3(&self, resolution: u8) -> Result<u64, N3gbError> {
+        let resolution =
+            Resolution::try_from(resolution).map_err(|e| N3gbError::H3Error(e.to_string()))?;
+        let wgs84 = convert_to_wgs84(&self.center, preferred_method())?;
+        let lat_lng =
+            LatLng::new(wgs84.y(), wgs84.x()).map_err(|e| N3gbError::H3Error(e.to_string()))?;
+        Ok(lat_lng.to_cell(resolution).into())
+    }
+
+    /// Checks whether this cell's center falls inside the given H3 cell.
+    ///
+    /// Requires the `h3` feature.
+    ///
+    /// # Arguments
+    /// * `h3_index` - The 64-bit H3 cell index to test against.
+    ///
+    /// # Returns
+    /// `true` if the H3 cell at `h3_index`'s resolution containing this cell's
+    /// center is `h3_index`.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::H3Error`] if `h3_index` is not a valid H3 cell index, and
+    /// [`N3gbError::ProjectionError`] if the center cannot be converted to WGS84.
+    pub fn is_within_h3(&self, h3_index: u64) -> Result<bool, N3gbError> {
+        let index = CellIndex::try_from(h3_index).map_err(|e| N3gbError::H3Error(e.to_string()))?;
+        Ok(self.to_h3(u8::from(index.resolution()))? == h3_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_h3_round_trip() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let h3_index = cell.to_h3(12)?;
+
+        let index = CellIndex::try_from(h3_index).map_err(|e| N3gbError::H3Error(e.to_string()))?;
+        assert_eq!(u8::from(index.resolution()), 12);
+
+        let h3_center = LatLng::from(index);
+        let center = convert_to_wgs84(&cell.center, preferred_method())?;
+        assert!((h3_center.lng() - center.x()).abs() < 1e-3);
+        assert!((h3_center.lat() - center.y()).abs() < 1e-3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_within_h3() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        let h3_index = cell.to_h3(9)?;
+        assert!(cell.is_within_h3(h3_index)?);
+
+        let far = HexCell::from_bng(&(530000.0, 180000.0), 12)?;
+        assert!(!far.is_within_h3(h3_index)?);
+
+        assert!(matches!(cell.to_h3(16), Err(N3gbError::H3Error(_))));
+        assert!(matches!(cell.is_within_h3(0), Err(N3gbError::H3Error(_))));
+        Ok(())
+    }
+}
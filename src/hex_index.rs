@@ -0,0 +1,346 @@
+use crate::cell::HexCell;
+use crate::error::N3gbError;
+use crate::index::{CELL_WIDTHS, offset_to_cube, point_to_row_col, row_col_to_center_unchecked};
+use geo::{Distance, Euclidean};
+use geo_types::Point;
+use std::collections::HashMap;
+
+/// A lookup structure over a fixed set of [`HexCell`]s.
+///
+/// Where [`HexGrid`](crate::HexGrid) generates the cells covering an area,
+/// `HexIndex` indexes cells you already have (e.g. loaded from a file or a
+/// database) for repeated lookups by ID, by containing point, and by nearest
+/// center. All cells must share one zoom level.
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::{HexCell, HexIndex};
+/// use geo_types::point;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let cells = vec![
+///     HexCell::from_bng(&(383640.0, 398260.0), 10)?,
+///     HexCell::from_bng(&(390000.0, 400000.0), 10)?,
+/// ];
+/// let index = HexIndex::new(cells.clone())?;
+///
+/// assert_eq!(index.get_by_id(&cells[0].id), Some(&cells[0]));
+/// assert_eq!(index.get_at_point(&point! { x: 383640.0, y: 398260.0 }), Some(&cells[0]));
+/// assert_eq!(index.nearest(&point! { x: 389000.0, y: 399500.0 }), Some(&cells[1]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexIndex {
+    cells: Vec<HexCell>,
+    by_id: HashMap<String, usize>,
+    by_row_col: HashMap<(i64, i64), usize>,
+    zoom_level: u8,
+}
+
+impl HexIndex {
+    /// Builds an index over a set of cells.
+    ///
+    /// If the same cell appears more than once, lookups return its first
+    /// occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The cells to index, all at the same zoom level.
+    ///
+    /// # Returns
+    ///
+    /// A `HexIndex` owning the cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ZoomLevelMismatch`] if the cells are not all at the
+    /// same zoom level.
+    pub fn new(cells: Vec<HexCell>) -> Result<Self, N3gbError> {
+        let zoom_level = cells.first().map_or(0, |cell| cell.zoom_level);
+        if let Some(cell) = cells.iter().find(|cell| cell.zoom_level != zoom_level) {
+            return Err(N3gbError::ZoomLevelMismatch(zoom_level, cell.zoom_level));
+        }
+
+        let mut by_id = HashMap::with_capacity(cells.len());
+        let mut by_row_col = HashMap::with_capacity(cells.len());
+        for (i, cell) in cells.iter().enumerate() {
+            by_id.entry(cell.id.clone()).or_insert(i);
+            by_row_col.entry((cell.row, cell.col)).or_insert(i);
+        }
+
+        Ok(Self {
+            cells,
+            by_id,
+            by_row_col,
+            zoom_level,
+        })
+    }
+
+    /// Returns the number of cells in the index.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if the index contains no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the indexed cells, in the order they were given.
+    pub fn cells(&self) -> &[HexCell] {
+        &self.cells
+    }
+
+    /// Looks up a cell by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The cell ID to find.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&HexCell)` with that ID, or `None` if it is not in the index.
+    pub fn get_by_id(&self, id: &str) -> Option<&HexCell> {
+        self.by_id.get(id).map(|&i| &self.cells[i])
+    }
+
+    /// Finds the indexed cell containing a point.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to locate, in BNG (EPSG:27700) coordinates.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&HexCell)` containing the point, or `None` if the cell containing
+    /// it is not in the index.
+    pub fn get_at_point(&self, point: &Point<f64>) -> Option<&HexCell> {
+        let (row, col) = point_to_row_col(point, self.zoom_level).ok()?;
+        self.by_row_col.get(&(row, col)).map(|&i| &self.cells[i])
+    }
+
+    /// Finds the indexed cell whose center is closest to a point.
+    ///
+    /// Searches outward ring by ring from the cell containing the point, so
+    /// queries near indexed cells only touch a few lattice positions. Once the
+    /// rings searched outnumber the indexed cells, it falls back to scanning
+    /// every cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The query point, in BNG (EPSG:27700) coordinates.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&HexCell)` with the nearest center, or `None` if the index is empty.
+    pub fn nearest(&self, point: &Point<f64>) -> Option<&HexCell> {
+        let distance = |cell: &&HexCell| Euclidean.distance(&cell.center, point);
+
+        let Ok((row, col)) = point_to_row_col(point, self.zoom_level) else {
+            return self
+                .cells
+                .iter()
+                .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+        };
+        // Centers k rings out are at least k * width * sqrt(3) / 2 from the
+        // home center. The point can sit further from the home center than one
+        // radius (up to a full width on odd rows), so measure the offset.
+        let offset = Euclidean.distance(
+            &row_col_to_center_unchecked(row, col, self.zoom_level),
+            point,
+        );
+        let ring_spacing = CELL_WIDTHS[self.zoom_level as usize] * 3f64.sqrt() / 2.0;
+        let (q0, r0, _) = offset_to_cube(row, col);
+
+        // Points are assigned by rectangle, not hexagon, so the home cell is
+        // only the first candidate; a neighbour's center can be closer
+        let mut best: Option<(&HexCell, f64)> = self.by_row_col.get(&(row, col)).map(|&i| {
+            let cell = &self.cells[i];
+            (cell, distance(&cell))
+        });
+        let mut visited = 1;
+        for k in 1_i64.. {
+            if let Some((_, d)) = best
+                && k as f64 * ring_spacing - offset > d
+            {
+                break;
+            }
+            if visited > self.cells.len() {
+                return self
+                    .cells
+                    .iter()
+                    .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+            }
+
+            for (row, col) in ring_row_cols(q0, r0, k) {
+                visited += 1;
+                if let Some(&i) = self.by_row_col.get(&(row, col)) {
+                    let cell = &self.cells[i];
+                    let d = distance(&cell);
+                    if best.is_none_or(|(_, best_d)| d < best_d) {
+                        best = Some((cell, d));
+                    }
+                }
+            }
+        }
+
+        best.map(|(cell, _)| cell)
+    }
+}
+
+/// Lists the `(row, col)` addresses exactly `k` steps from a cube coordinate.
+///
+/// # Arguments
+///
+/// * `q0` - The cube `q` coordinate of the center.
+/// * `r0` - The cube `r` coordinate (row) of the center.
+/// * `k` - The ring radius, at least 1.
+///
+/// # Returns
+///
+/// The `6k` addresses on the ring, ordered by row.
+fn ring_row_cols(q0: i64, r0: i64, k: i64) -> impl Iterator<Item = (i64, i64)> {
+    (-k..=k).flat_map(move |dr| {
+        let (min_dq, max_dq) = ((-k).max(-dr - k), k.min(k - dr));
+        let row = r0 + dr;
+        let dqs: Vec<i64> = if dr.abs() == k {
+            (min_dq..=max_dq).collect()
+        } else {
+            vec![min_dq, max_dq]
+        };
+        dqs.into_iter()
+            .map(move |dq| (row, q0 + dq + row.div_euclid(2)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::HexGrid;
+    use geo_types::point;
+
+    fn sample_cells() -> Result<Vec<HexCell>, N3gbError> {
+        [
+            (383640.0, 398260.0),
+            (385000.0, 398260.0),
+            (390000.0, 405000.0),
+            (457500.0, 340000.0),
+        ]
+        .iter()
+        .map(|coord| HexCell::from_bng(coord, 10))
+        .collect()
+    }
+
+    #[test]
+    fn test_get_by_id() -> Result<(), N3gbError> {
+        let cells = sample_cells()?;
+        let index = HexIndex::new(cells.clone())?;
+
+        assert_eq!(index.len(), 4);
+        for cell in &cells {
+            assert_eq!(index.get_by_id(&cell.id), Some(cell));
+        }
+        let other = HexCell::from_bng(&(400000.0, 400000.0), 10)?;
+        assert_eq!(index.get_by_id(&other.id), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_at_point() -> Result<(), N3gbError> {
+        let cells = sample_cells()?;
+        let index = HexIndex::new(cells.clone())?;
+
+        for cell in &cells {
+            let inside = point! { x: cell.easting() + 5.0, y: cell.northing() - 5.0 };
+            assert_eq!(index.get_at_point(&inside), Some(cell));
+        }
+        assert_eq!(
+            index.get_at_point(&point! { x: 400000.0, y: 400000.0 }),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nearest_matches_linear_scan() -> Result<(), N3gbError> {
+        let cells = sample_cells()?;
+        let index = HexIndex::new(cells.clone())?;
+
+        let queries = [
+            point! { x: 383640.0, y: 398260.0 },
+            point! { x: 384400.0, y: 398300.0 },
+            point! { x: 388000.0, y: 403000.0 },
+            point! { x: 440000.0, y: 350000.0 },
+            point! { x: 100000.0, y: 1000000.0 },
+            point! { x: -5000.0, y: -5000.0 },
+        ];
+        for query in &queries {
+            let expected = cells.iter().min_by(|a, b| {
+                Euclidean
+                    .distance(&a.center, query)
+                    .total_cmp(&Euclidean.distance(&b.center, query))
+            });
+            assert_eq!(index.nearest(query), expected, "query {:?}", query);
+        }
+
+        // Near a corner of its row/column rectangle, a point on an odd row is
+        // almost a full cell width from its home center, so the nearest cell
+        // can lie a ring further out than the first one searched
+        let corner = [(260260.0, 112583.302), (260455.0, 112695.886)]
+            .iter()
+            .map(|coord| HexCell::from_bng(coord, 10))
+            .collect::<Result<Vec<_>, _>>()?;
+        let index = HexIndex::new(corner.clone())?;
+        let query = point! { x: 260318.5, y: 112710.0 };
+        assert_eq!(index.nearest(&query), Some(&corner[1]));
+
+        // The cell a point is assigned to need not have the nearest center:
+        // this point lies in col 2949's rectangle but 1m from col 2950's center
+        let pair = [(383564.0, 398207.141), (383566.0, 398207.141)]
+            .iter()
+            .map(|coord| HexCell::from_bng(coord, 10))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!((pair[0].row, pair[0].col), (3537, 2949));
+        assert_eq!((pair[1].row, pair[1].col), (3537, 2950));
+        let index = HexIndex::new(pair.clone())?;
+        let query = point! { x: 383564.0, y: 398207.141 };
+        assert_eq!(index.get_at_point(&query), Some(&pair[0]));
+        assert_eq!(index.nearest(&query), Some(&pair[1]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nearest_on_dense_grid() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.0, 340500.0), 10)?;
+        let index = HexIndex::new(grid.cells().to_vec())?;
+
+        // A point just outside the grid snaps to a cell on its edge
+        let query = point! { x: 458400.0, y: 340000.0 };
+        let nearest = index.nearest(&query).unwrap();
+        let expected = grid
+            .iter()
+            .map(|cell| Euclidean.distance(&cell.center, &query))
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(Euclidean.distance(&nearest.center, &query), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_and_mixed_zoom() -> Result<(), N3gbError> {
+        let empty = HexIndex::new(Vec::new())?;
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(&point! { x: 383640.0, y: 398260.0 }), None);
+
+        let mixed = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 10)?,
+            HexCell::from_bng(&(383640.0, 398260.0), 11)?,
+        ];
+        assert_eq!(
+            HexIndex::new(mixed).err(),
+            Some(N3gbError::ZoomLevelMismatch(10, 11))
+        );
+        Ok(())
+    }
+}
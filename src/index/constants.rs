@@ -1,6 +1,26 @@
+use geo_types::LineString;
+
 /// Identifier version for encoding/decoding
 pub const IDENTIFIER_VERSION: u8 = 1;
 
+/// Identifier version for whole-metre identifiers
+pub const IDENTIFIER_VERSION_METRE: u8 = 2;
+
+/// Identifier version for millimetre identifiers without a checksum
+pub const IDENTIFIER_VERSION_UNCHECKED: u8 = 3;
+
+/// Length in characters of a version 1 (millimetre) identifier
+///
+/// Identifiers are fixed-width unpadded base64 of a fixed-length payload, so
+/// every identifier of a version has the same length at every zoom level.
+pub const IDENTIFIER_LENGTH: usize = 26;
+
+/// Length in characters of a version 2 (whole-metre) identifier
+pub const IDENTIFIER_LENGTH_METRE: usize = 15;
+
+/// Length in characters of a version 3 (unchecked) identifier
+pub const IDENTIFIER_LENGTH_UNCHECKED: usize = 24;
+
 /// Scale factor to preserve three decimal places
 pub(crate) const SCALE_FACTOR: u64 = 1000;
 
@@ -8,6 +28,10 @@ pub(crate) const SCALE_FACTOR: u64 = 1000;
 pub const GRID_EXTENTS: [f64; 4] = [0.0, 0.0, 750000.0, 1350000.0];
 
 /// Cell radius for each zoom level (0-15)
+///
+/// Each radius is the matching [`CELL_WIDTHS`] entry divided by `sqrt(3)`, so
+/// the hexagons drawn from the radius tile exactly at the column spacing used
+/// to index points.
 pub const CELL_RADIUS: [f64; 16] = [
     1281249.9438829257,
     483045.8762201923,
@@ -35,3 +59,155 @@ pub const CELL_WIDTHS: [f64; 16] = [
 
 /// Maximum zoom level
 pub const MAX_ZOOM_LEVEL: u8 = 15;
+
+/// Maximum number of cells a single grid may be generated with
+pub const MAX_GRID_CELLS: u64 = 50_000_000;
+
+/// Metrics describing the cells at a single zoom level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoomInfo {
+    /// Zoom level (0-15)
+    pub zoom: u8,
+    /// Circumradius (center to vertex) in meters
+    pub radius_m: f64,
+    /// Cell width (flat to flat) in meters, as used for column spacing
+    pub width_m: f64,
+    /// Area of a single cell in square meters
+    pub area_m2: f64,
+    /// Approximate number of cells needed to cover [`GRID_EXTENTS`]
+    pub approx_cell_count: u64,
+}
+
+/// Lists every zoom level alongside its cell metrics.
+///
+/// Collects [`CELL_RADIUS`] and [`CELL_WIDTHS`] into a single queryable table,
+/// e.g. for populating a zoom selector.
+///
+/// # Returns
+/// One [`ZoomInfo`] per zoom level, ordered from zoom 0 to [`MAX_ZOOM_LEVEL`].
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::zoom_table;
+///
+/// let table = zoom_table();
+/// assert_eq!(table.len(), 16);
+/// assert_eq!(table[12].zoom, 12);
+/// ```
+pub fn zoom_table() -> Vec<ZoomInfo> {
+    let extent_area = (GRID_EXTENTS[2] - GRID_EXTENTS[0]) * (GRID_EXTENTS[3] - GRID_EXTENTS[1]);
+
+    (0..=MAX_ZOOM_LEVEL)
+        .map(|zoom| {
+            let radius_m = CELL_RADIUS[zoom as usize];
+            let area_m2 = 1.5 * 3f64.sqrt() * radius_m * radius_m;
+            ZoomInfo {
+                zoom,
+                radius_m,
+                width_m: CELL_WIDTHS[zoom as usize],
+                area_m2,
+                approx_cell_count: (extent_area / area_m2).ceil() as u64,
+            }
+        })
+        .collect()
+}
+
+/// Number of cells each segment should span at the zoom picked by [`suggest_zoom_for_line`].
+const CELLS_PER_SEGMENT: f64 = 4.0;
+
+/// Suggests a zoom level for indexing a line, based on its typical segment length.
+///
+/// Picks the coarsest zoom level whose cell width is at most a quarter of the
+/// mean segment length, so a typical segment crosses several cells without the
+/// line being heavily oversampled.
+///
+/// # Arguments
+/// * `line` - The line, in BNG (EPSG:27700) coordinates.
+///
+/// # Returns
+/// The suggested zoom level. [`MAX_ZOOM_LEVEL`] if the line has no segments of
+/// non-zero length or its segments are shorter than the finest cells allow.
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::suggest_zoom_for_line;
+/// use geo_types::LineString;
+///
+/// let line = LineString::from(vec![(457000.0, 339500.0), (457400.0, 339500.0)]);
+/// assert_eq!(suggest_zoom_for_line(&line), 11);
+/// ```
+pub fn suggest_zoom_for_line(line: &LineString<f64>) -> u8 {
+    let segments = line.lines().count();
+    if segments == 0 {
+        return MAX_ZOOM_LEVEL;
+    }
+
+    let mean_length = line
+        .lines()
+        .map(|segment| segment.dx().hypot(segment.dy()))
+        .sum::<f64>()
+        / segments as f64;
+    let target_width = mean_length / CELLS_PER_SEGMENT;
+
+    (0..=MAX_ZOOM_LEVEL)
+        .find(|&zoom| target_width > 0.0 && CELL_WIDTHS[zoom as usize] <= target_width)
+        .unwrap_or(MAX_ZOOM_LEVEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_table() {
+        let table = zoom_table();
+        assert_eq!(table.len(), 16);
+
+        for (i, info) in table.iter().enumerate() {
+            assert_eq!(info.zoom as usize, i);
+        }
+        for pair in table.windows(2) {
+            assert!(pair[1].radius_m < pair[0].radius_m);
+            assert!(pair[1].area_m2 < pair[0].area_m2);
+            assert!(pair[1].approx_cell_count >= pair[0].approx_cell_count);
+        }
+    }
+
+    #[test]
+    fn test_widths_match_radii() {
+        // Pointy-top hexagons are sqrt(3) * radius wide across their flat sides
+        for (zoom, (&width, &radius)) in CELL_WIDTHS.iter().zip(&CELL_RADIUS).enumerate() {
+            let derived = 3f64.sqrt() * radius;
+            assert!(
+                (derived - width).abs() <= width * 1e-12,
+                "zoom {}: sqrt(3) * {} = {} but width is {}",
+                zoom,
+                radius,
+                derived,
+                width
+            );
+        }
+    }
+
+    #[test]
+    fn test_suggest_zoom_for_line() {
+        // Mean segment length 400 m: the coarsest width <= 100 m is 49 m (zoom 11).
+        let line = LineString::from(vec![
+            (457000.0, 339500.0),
+            (457300.0, 339500.0),
+            (457300.0, 340000.0),
+        ]);
+        assert_eq!(suggest_zoom_for_line(&line), 11);
+
+        // Longer segments pick coarser cells.
+        let long = LineString::from(vec![(400000.0, 300000.0), (440000.0, 300000.0)]);
+        assert_eq!(suggest_zoom_for_line(&long), 6);
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(suggest_zoom_for_line(&empty), MAX_ZOOM_LEVEL);
+        let tiny = LineString::from(vec![(457000.0, 339500.0), (457000.5, 339500.0)]);
+        assert_eq!(suggest_zoom_for_line(&tiny), MAX_ZOOM_LEVEL);
+    }
+}
@@ -1,7 +1,113 @@
 use crate::error::N3gbError;
-use crate::index::constants::{IDENTIFIER_VERSION, SCALE_FACTOR};
+use crate::index::constants::{
+    CELL_RADIUS, CELL_WIDTHS, IDENTIFIER_VERSION, IDENTIFIER_VERSION_METRE,
+    IDENTIFIER_VERSION_UNCHECKED, SCALE_FACTOR,
+};
+use crate::parallel::*;
 use base64::Engine;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use geo_types::Point;
+
+/// Precision of the coordinates stored in a hex cell identifier.
+///
+/// The precision is recorded in the identifier's version byte, so
+/// [`decode_hex_identifier`] reads either kind without being told which it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdentifierPrecision {
+    /// Millimetre precision (version 1, 19 bytes, 26 characters).
+    #[default]
+    Millimetre,
+    /// Whole-metre precision (version 2, 11 bytes, 15 characters).
+    ///
+    /// Coordinates within the same metre share an identifier. Not available at
+    /// zoom level 15, whose cells are too small to tell apart by whole metres.
+    Metre,
+}
+
+impl IdentifierPrecision {
+    /// The version byte written for this precision.
+    pub fn version(self) -> u8 {
+        match self {
+            IdentifierPrecision::Millimetre => IDENTIFIER_VERSION,
+            IdentifierPrecision::Metre => IDENTIFIER_VERSION_METRE,
+        }
+    }
+
+    /// Looks up the precision recorded by a version byte.
+    ///
+    /// Both the checked (version 1) and unchecked (version 3) millimetre
+    /// layouts map to [`IdentifierPrecision::Millimetre`].
+    ///
+    /// # Returns
+    ///
+    /// `None` if `version` is not a known identifier version.
+    pub fn from_version(version: u8) -> Option<Self> {
+        match version {
+            IDENTIFIER_VERSION | IDENTIFIER_VERSION_UNCHECKED => {
+                Some(IdentifierPrecision::Millimetre)
+            }
+            IDENTIFIER_VERSION_METRE => Some(IdentifierPrecision::Metre),
+            _ => None,
+        }
+    }
+
+    /// Whether a center stored at this precision still singles out its cell.
+    ///
+    /// Rounding to whole metres moves a center by up to half a metre on each
+    /// axis, which only identifies the cell while the row spacing and cell
+    /// width both exceed a metre. That rules out zoom level 15.
+    pub(crate) fn resolves_cells(self, zoom_level: u8) -> bool {
+        match self {
+            IdentifierPrecision::Millimetre => true,
+            IdentifierPrecision::Metre => CELL_WIDTHS
+                .get(zoom_level as usize)
+                .is_none_or(|&width| width > 1.0 && 1.5 * CELL_RADIUS[zoom_level as usize] > 1.0),
+        }
+    }
+
+    /// Rounds a coordinate to the integer stored at this precision.
+    pub(crate) fn quantize(self, value: f64) -> u64 {
+        (value * self.scale()).round() as u64
+    }
+
+    fn scale(self) -> f64 {
+        match self {
+            IdentifierPrecision::Millimetre => SCALE_FACTOR as f64,
+            IdentifierPrecision::Metre => 1.0,
+        }
+    }
+
+    fn coordinate_bytes(self) -> usize {
+        match self {
+            IdentifierPrecision::Millimetre => 8,
+            IdentifierPrecision::Metre => 4,
+        }
+    }
+
+    /// Length of version, coordinates and zoom level, excluding any checksum.
+    fn payload_len(self) -> usize {
+        1 + 2 * self.coordinate_bytes() + 1
+    }
+}
+
+/// Looks up the coordinate precision and whether a checksum byte follows for a version.
+fn identifier_layout(version: u8) -> Option<(IdentifierPrecision, bool)> {
+    IdentifierPrecision::from_version(version)
+        .map(|precision| (precision, version != IDENTIFIER_VERSION_UNCHECKED))
+}
+
+/// Scales a BNG coordinate to the integer value stored in the identifier.
+///
+/// # Arguments
+///
+/// * `value` - The BNG easting or northing in meters.
+///
+/// # Returns
+///
+/// The coordinate multiplied by `SCALE_FACTOR` and rounded, as `u64`.
+pub(crate) fn scale_coordinate(value: f64) -> u64 {
+    IdentifierPrecision::Millimetre.quantize(value)
+}
 
 /// Generates a unique hex cell identifier from BNG coordinates and zoom level.
 ///
@@ -17,6 +123,11 @@ use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 ///
 /// A URL-safe Base64 `String` (no padding) encoding the 19-byte identifier.
 ///
+/// # Errors
+///
+/// Returns [`N3gbError::OutOfBounds`] if either coordinate is negative or not finite,
+/// since it cannot be stored as an unsigned scaled integer.
+///
 /// # Binary Format
 ///
 /// The identifier encodes the following data in big-endian byte order:
@@ -31,32 +142,241 @@ use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 ///
 /// # Process
 ///
-/// 1. Multiplies coordinates by `SCALE_FACTOR` and rounds to preserve precision
-/// 2. Packs version, scaled coordinates, and zoom level into 18 bytes
-/// 3. Computes a checksum by summing all bytes (with wrapping)
-/// 4. Appends the checksum byte
-/// 5. Encodes the 19 bytes as URL-safe Base64 (no padding)
+/// 1. Rejects negative or non-finite coordinates
+/// 2. Multiplies coordinates by `SCALE_FACTOR` and rounds to preserve precision
+/// 3. Packs version, scaled coordinates, and zoom level into 18 bytes
+/// 4. Computes a checksum by summing all bytes (with wrapping)
+/// 5. Appends the checksum byte
+/// 6. Encodes the 19 bytes as URL-safe Base64 (no padding)
 ///
 /// # Example
 /// ```
 /// use n3gb_rs::generate_hex_identifier;
 ///
-/// let id = generate_hex_identifier(457500.0, 340000.0, 10);
+/// let id = generate_hex_identifier(457500.0, 340000.0, 10).unwrap();
 /// assert!(!id.is_empty());
 /// println!("{}", id);
+///
+/// assert!(generate_hex_identifier(-10.0, 340000.0, 10).is_err());
+/// ```
+pub fn generate_hex_identifier(
+    easting: f64,
+    northing: f64,
+    zoom_level: u8,
+) -> Result<String, N3gbError> {
+    generate_hex_identifier_with_precision(
+        easting,
+        northing,
+        zoom_level,
+        IdentifierPrecision::Millimetre,
+    )
+}
+
+/// Generates the identifier for a cell center already known to lie in the grid.
+///
+/// Used by grid construction, where centers come from the lattice and have
+/// been checked against [`GRID_EXTENTS`](crate::GRID_EXTENTS), so encoding
+/// cannot fail and no cell is dropped.
+///
+/// # Arguments
+///
+/// * `center` - A cell center with non-negative, finite coordinates.
+/// * `zoom_level` - An already validated zoom level.
+///
+/// # Returns
+///
+/// The same identifier [`generate_hex_identifier`] would produce.
+///
+/// # Panics
+///
+/// Panics if either coordinate of `center` is negative or not finite.
+pub(crate) fn grid_cell_identifier(center: &Point<f64>, zoom_level: u8) -> String {
+    encode_identifier(
+        center.x(),
+        center.y(),
+        zoom_level,
+        IdentifierPrecision::Millimetre,
+        IDENTIFIER_VERSION,
+        true,
+    )
+    .expect("grid cell centers are non-negative and finite")
+}
+
+/// Generates a hex cell identifier with the given coordinate precision.
+///
+/// With [`IdentifierPrecision::Millimetre`] this is identical to
+/// [`generate_hex_identifier`]. With [`IdentifierPrecision::Metre`] the
+/// coordinates are rounded to whole metres and stored as `u32`, giving a
+/// shorter identifier with version byte 2:
+///
+/// | Offset | Size | Field       | Description                                      |
+/// |--------|------|-------------|--------------------------------------------------|
+/// | 0      | 1    | Version     | `IDENTIFIER_VERSION_METRE` (2)                   |
+/// | 1      | 4    | Easting     | BNG easting rounded to metres as `u32`           |
+/// | 5      | 4    | Northing    | BNG northing rounded to metres as `u32`          |
+/// | 9      | 1    | Zoom Level  | Grid zoom level (0-15)                           |
+/// | 10     | 1    | Checksum    | Wrapping sum of bytes 0-9 for validation         |
+///
+/// # Arguments
+///
+/// * `easting` - The BNG easting coordinate.
+/// * `northing` - The BNG northing coordinate.
+/// * `zoom_level` - The grid zoom level (0-15).
+/// * `precision` - The precision at which to store the coordinates.
+///
+/// # Returns
+///
+/// A URL-safe Base64 `String` (no padding) encoding the identifier.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::OutOfBounds`] if either coordinate is negative or not finite,
+/// or too large to store at metre precision, and [`N3gbError::InvalidZoomLevel`] for
+/// metre precision at zoom level 15, where whole metres cannot identify a cell.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{IdentifierPrecision, decode_hex_identifier, generate_hex_identifier_with_precision};
+///
+/// let a = generate_hex_identifier_with_precision(457500.2, 340000.4, 10, IdentifierPrecision::Metre).unwrap();
+/// let b = generate_hex_identifier_with_precision(457499.9, 339999.8, 10, IdentifierPrecision::Metre).unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(a.len(), 15);
+///
+/// let (version, easting, northing, zoom) = decode_hex_identifier(&a).unwrap();
+/// assert_eq!((version, easting, northing, zoom), (2, 457500.0, 340000.0, 10));
+/// ```
+pub fn generate_hex_identifier_with_precision(
+    easting: f64,
+    northing: f64,
+    zoom_level: u8,
+    precision: IdentifierPrecision,
+) -> Result<String, N3gbError> {
+    if !precision.resolves_cells(zoom_level) {
+        return Err(N3gbError::InvalidZoomLevel(zoom_level));
+    }
+    encode_identifier(
+        easting,
+        northing,
+        zoom_level,
+        precision,
+        precision.version(),
+        true,
+    )
+}
+
+/// Generates a millimetre-precision identifier without a checksum byte.
+///
+/// Uses version byte 3 and drops the trailing checksum, giving 18 bytes (24
+/// characters instead of 26) and skipping checksum work on decode.
+/// [`decode_hex_identifier`] recognises the version and decodes it normally.
+///
+/// Only use these IDs inside a trusted pipeline. Without a checksum, a
+/// corrupted or mistyped ID can decode to a different valid cell instead of
+/// being rejected, so never accept them from untrusted input.
+///
+/// # Arguments
+///
+/// * `easting` - The BNG easting coordinate.
+/// * `northing` - The BNG northing coordinate.
+/// * `zoom_level` - The grid zoom level (0-15).
+///
+/// # Returns
+///
+/// A URL-safe Base64 `String` (no padding) encoding the 18-byte identifier.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::OutOfBounds`] if either coordinate is negative or not finite.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{decode_hex_identifier, generate_hex_identifier, generate_hex_identifier_unchecked};
+///
+/// let id = generate_hex_identifier_unchecked(457500.0, 340000.0, 10).unwrap();
+/// assert_eq!(id.len(), 24);
+/// assert!(id.len() < generate_hex_identifier(457500.0, 340000.0, 10).unwrap().len());
+///
+/// let (version, easting, northing, zoom) = decode_hex_identifier(&id).unwrap();
+/// assert_eq!((version, easting, northing, zoom), (3, 457500.0, 340000.0, 10));
 /// ```
-pub fn generate_hex_identifier(easting: f64, northing: f64, zoom_level: u8) -> String {
-    let easting_int = (easting * SCALE_FACTOR as f64).round() as u64;
-    let northing_int = (northing * SCALE_FACTOR as f64).round() as u64;
-
-    let mut buf = [0u8; 19];
-    buf[0] = IDENTIFIER_VERSION;
-    buf[1..9].copy_from_slice(&easting_int.to_be_bytes());
-    buf[9..17].copy_from_slice(&northing_int.to_be_bytes());
-    buf[17] = zoom_level;
-    buf[18] = buf[..18].iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
-
-    URL_SAFE_NO_PAD.encode(buf)
+pub fn generate_hex_identifier_unchecked(
+    easting: f64,
+    northing: f64,
+    zoom_level: u8,
+) -> Result<String, N3gbError> {
+    encode_identifier(
+        easting,
+        northing,
+        zoom_level,
+        IdentifierPrecision::Millimetre,
+        IDENTIFIER_VERSION_UNCHECKED,
+        false,
+    )
+}
+
+/// Packs and Base64-encodes an identifier.
+///
+/// # Arguments
+///
+/// * `easting` - The BNG easting coordinate.
+/// * `northing` - The BNG northing coordinate.
+/// * `zoom_level` - The grid zoom level (0-15).
+/// * `precision` - The precision at which to store the coordinates.
+/// * `version` - The version byte to write.
+/// * `checksum` - Whether to append the checksum byte.
+///
+/// # Returns
+///
+/// The URL-safe Base64 encoded identifier.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::OutOfBounds`] if either coordinate is negative or not finite,
+/// or too large to store at metre precision.
+fn encode_identifier(
+    easting: f64,
+    northing: f64,
+    zoom_level: u8,
+    precision: IdentifierPrecision,
+    version: u8,
+    checksum: bool,
+) -> Result<String, N3gbError> {
+    for (name, value) in [("easting", easting), ("northing", northing)] {
+        if !value.is_finite() || value < 0.0 {
+            return Err(N3gbError::OutOfBounds(format!(
+                "{} {} cannot be encoded in an identifier",
+                name, value
+            )));
+        }
+    }
+
+    let mut buf = Vec::with_capacity(precision.payload_len() + 1);
+    buf.push(version);
+    match precision {
+        IdentifierPrecision::Millimetre => {
+            buf.extend_from_slice(&scale_coordinate(easting).to_be_bytes());
+            buf.extend_from_slice(&scale_coordinate(northing).to_be_bytes());
+        }
+        IdentifierPrecision::Metre => {
+            for (name, value) in [("easting", easting), ("northing", northing)] {
+                let metres = value.round();
+                if metres > u32::MAX as f64 {
+                    return Err(N3gbError::OutOfBounds(format!(
+                        "{} {} is too large for a metre-precision identifier",
+                        name, value
+                    )));
+                }
+                buf.extend_from_slice(&(metres as u32).to_be_bytes());
+            }
+        }
+    }
+    buf.push(zoom_level);
+    if checksum {
+        buf.push(buf.iter().fold(0u8, |acc, &b| acc.wrapping_add(b)));
+    }
+
+    Ok(URL_SAFE_NO_PAD.encode(buf))
 }
 
 /// Decodes a hex cell identifier back to its component parts.
@@ -70,12 +390,16 @@ pub fn generate_hex_identifier(easting: f64, northing: f64, zoom_level: u8) -> S
 ///
 /// # Process
 ///
-/// 1. Decodes the Base64 string to 19 bytes
-/// 2. Validates the length is exactly 19 bytes
-/// 3. Extracts and verifies the checksum (last byte) against bytes 0-17
-/// 4. Extracts the version byte and validates it matches the current version
-/// 5. Reads the 8-byte easting and northing values (big-endian `u64`)
-/// 6. Divides by `SCALE_FACTOR` to restore the original `f64` coordinates
+/// 1. Decodes the Base64 string to 19 bytes (millimetre), 18 bytes (millimetre,
+///    no checksum) or 11 bytes (metre)
+/// 2. Validates the length; the decoder rejects trailing bits and padding, so
+///    each cell has exactly one accepted encoding
+/// 3. Extracts the version byte and validates it is a known version whose
+///    layout matches the length
+/// 4. Verifies the checksum (last byte) against the preceding bytes, unless the
+///    version has none
+/// 5. Reads the easting and northing values (big-endian `u64`, or `u32` for metre precision)
+/// 6. Divides by the version's scale factor to restore the original `f64` coordinates
 /// 7. Extracts the zoom level byte
 ///
 /// # Returns
@@ -86,7 +410,7 @@ pub fn generate_hex_identifier(easting: f64, northing: f64, zoom_level: u8) -> S
 /// ```
 /// use n3gb_rs::{generate_hex_identifier, decode_hex_identifier};
 ///
-/// let id = generate_hex_identifier(457500.0, 340000.0, 10);
+/// let id = generate_hex_identifier(457500.0, 340000.0, 10).unwrap();
 /// let (version, easting, northing, zoom) = decode_hex_identifier(&id).unwrap();
 ///
 /// assert_eq!(version, 1);
@@ -97,48 +421,87 @@ pub fn generate_hex_identifier(easting: f64, northing: f64, zoom_level: u8) -> S
 ///
 /// # Errors
 ///
-/// - [`N3gbError::Base64DecodeError`] - Invalid Base64 encoding
-/// - [`N3gbError::InvalidIdentifierLength`] - Decoded data is not 19 bytes
+/// - [`N3gbError::Base64DecodeError`] - Invalid or non-canonical Base64 encoding
+/// - [`N3gbError::InvalidIdentifierLength`] - Decoded data is not 19, 18 or 11 bytes, or
+///   its length does not match the layout of its version
 /// - [`N3gbError::InvalidChecksum`] - Checksum validation failed
-/// - [`N3gbError::UnsupportedVersion`] - Version byte doesn't match current version
+/// - [`N3gbError::UnsupportedVersion`] - Version byte is not a known version
 pub fn decode_hex_identifier(identifier: &str) -> Result<(u8, f64, f64, u8), N3gbError> {
     let binary_data = URL_SAFE_NO_PAD
         .decode(identifier)
         .map_err(|_| N3gbError::Base64DecodeError)?;
 
-    if binary_data.len() != 19 {
+    let valid_lengths = [
+        IdentifierPrecision::Millimetre.payload_len() + 1,
+        IdentifierPrecision::Millimetre.payload_len(),
+        IdentifierPrecision::Metre.payload_len() + 1,
+    ];
+    if !valid_lengths.contains(&binary_data.len()) {
         return Err(N3gbError::InvalidIdentifierLength);
     }
 
-    let (data, checksum_bytes) = binary_data.split_at(18);
-    let checksum = checksum_bytes[0];
-
-    let calculated_checksum: u8 = data.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
-    if calculated_checksum != checksum {
-        return Err(N3gbError::InvalidChecksum);
+    let version = binary_data[0];
+    let (precision, checksummed) =
+        identifier_layout(version).ok_or(N3gbError::UnsupportedVersion(version))?;
+    if binary_data.len() != precision.payload_len() + checksummed as usize {
+        return Err(N3gbError::InvalidIdentifierLength);
     }
 
-    let version = data[0];
-    let easting_bytes: [u8; 8] = data[1..9]
-        .try_into()
-        .map_err(|_| N3gbError::InvalidIdentifierLength)?;
-    let northing_bytes: [u8; 8] = data[9..17]
-        .try_into()
-        .map_err(|_| N3gbError::InvalidIdentifierLength)?;
-    let easting_int = u64::from_be_bytes(easting_bytes);
-    let northing_int = u64::from_be_bytes(northing_bytes);
-    let zoom = data[17];
+    let data = if checksummed {
+        let (data, checksum_bytes) = binary_data.split_at(binary_data.len() - 1);
+        let calculated_checksum: u8 = data.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        if calculated_checksum != checksum_bytes[0] {
+            return Err(N3gbError::InvalidChecksum);
+        }
+        data
+    } else {
+        &binary_data[..]
+    };
 
-    if version != IDENTIFIER_VERSION {
-        return Err(N3gbError::UnsupportedVersion(version));
-    }
+    let width = precision.coordinate_bytes();
+    // Big-endian unsigned integer of either width
+    let read = |bytes: &[u8]| bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+    let easting_int = read(&data[1..1 + width]);
+    let northing_int = read(&data[1 + width..1 + 2 * width]);
+    let zoom = data[1 + 2 * width];
 
-    let easting = easting_int as f64 / SCALE_FACTOR as f64;
-    let northing = northing_int as f64 / SCALE_FACTOR as f64;
+    let easting = easting_int as f64 / precision.scale();
+    let northing = northing_int as f64 / precision.scale();
 
     Ok((version, easting, northing, zoom))
 }
 
+/// Decodes many hex cell identifiers at once.
+///
+/// Runs [`decode_hex_identifier`] over every ID, on rayon when the `parallel`
+/// feature is enabled. An invalid ID yields an `Err` in its own slot without
+/// affecting the others.
+///
+/// # Arguments
+///
+/// * `ids` - The identifiers to decode.
+///
+/// # Returns
+///
+/// One `(version, easting, northing, zoom_level)` result per input ID, in the
+/// same order as `ids`.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{decode_identifiers, generate_hex_identifier};
+///
+/// let ids = vec![
+///     generate_hex_identifier(457500.0, 340000.0, 10).unwrap(),
+///     "invalid".to_string(),
+/// ];
+/// let decoded = decode_identifiers(&ids);
+/// assert!(decoded[0].is_ok());
+/// assert!(decoded[1].is_err());
+/// ```
+pub fn decode_identifiers(ids: &[String]) -> Vec<Result<(u8, f64, f64, u8), N3gbError>> {
+    ids.par_iter().map(|id| decode_hex_identifier(id)).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,7 +512,7 @@ mod tests {
         let northing = 847702.123;
         let zoom = 10;
 
-        let id = generate_hex_identifier(easting, northing, zoom);
+        let id = generate_hex_identifier(easting, northing, zoom)?;
         assert!(!id.is_empty());
 
         let (version, decoded_e, decoded_n, decoded_z) = decode_hex_identifier(&id)?;
@@ -161,15 +524,207 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_negative_coordinate_rejected() {
+        assert!(matches!(
+            generate_hex_identifier(-0.5, 340000.0, 10),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            generate_hex_identifier(457500.0, -1.0, 10),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            generate_hex_identifier(f64::NAN, 340000.0, 10),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        assert!(generate_hex_identifier(0.0, 0.0, 10).is_ok());
+    }
+
+    #[test]
+    fn test_metre_precision_identifier() -> Result<(), N3gbError> {
+        let metre =
+            |e, n| generate_hex_identifier_with_precision(e, n, 10, IdentifierPrecision::Metre);
+
+        let id = metre(457500.2, 340000.4)?;
+        assert_eq!(id, metre(457499.6, 339999.5)?);
+        assert_eq!(id, metre(457500.0, 340000.0)?);
+        assert_ne!(id, metre(457501.0, 340000.0)?);
+
+        let mm = generate_hex_identifier(457500.0, 340000.0, 10)?;
+        assert!(id.len() < mm.len());
+        assert_ne!(id, mm);
+
+        assert_eq!(
+            decode_hex_identifier(&id)?,
+            (IDENTIFIER_VERSION_METRE, 457500.0, 340000.0, 10)
+        );
+        assert_eq!(
+            generate_hex_identifier_with_precision(
+                457500.2,
+                340000.4,
+                10,
+                IdentifierPrecision::Millimetre
+            )?,
+            generate_hex_identifier(457500.2, 340000.4, 10)?
+        );
+
+        assert!(matches!(
+            metre(-1.0, 340000.0),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            metre(1e12, 340000.0),
+            Err(N3gbError::OutOfBounds(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unchecked_identifier_round_trip() -> Result<(), N3gbError> {
+        for (easting, northing, zoom) in [
+            (457500.0, 340000.0, 10),
+            (252086.123, 847702.123, 15),
+            (0.0, 0.0, 0),
+        ] {
+            let id = generate_hex_identifier_unchecked(easting, northing, zoom)?;
+            let checked = generate_hex_identifier(easting, northing, zoom)?;
+            assert!(id.len() < checked.len());
+
+            let (version, decoded_e, decoded_n, decoded_z) = decode_hex_identifier(&id)?;
+            assert_eq!(version, IDENTIFIER_VERSION_UNCHECKED);
+            assert_eq!(
+                IdentifierPrecision::from_version(version),
+                Some(IdentifierPrecision::Millimetre)
+            );
+            assert_eq!((decoded_e, decoded_n, decoded_z), {
+                let (_, e, n, z) = decode_hex_identifier(&checked)?;
+                (e, n, z)
+            });
+        }
+
+        // The same 18 bytes under the checked version are missing their checksum
+        let id = generate_hex_identifier_unchecked(457500.0, 340000.0, 10)?;
+        let mut bytes = URL_SAFE_NO_PAD.decode(&id).unwrap();
+        bytes[0] = IDENTIFIER_VERSION;
+        assert_eq!(
+            decode_hex_identifier(&URL_SAFE_NO_PAD.encode(&bytes)),
+            Err(N3gbError::InvalidIdentifierLength)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_identifier_lengths() -> Result<(), N3gbError> {
+        use crate::index::constants::{
+            IDENTIFIER_LENGTH, IDENTIFIER_LENGTH_METRE, IDENTIFIER_LENGTH_UNCHECKED,
+        };
+
+        for zoom in 0..=15 {
+            for (easting, northing) in [(0.0, 0.0), (457500.123, 340000.456), (749999.999, 1.0e9)] {
+                assert_eq!(
+                    generate_hex_identifier(easting, northing, zoom)?.len(),
+                    IDENTIFIER_LENGTH
+                );
+                // Metre precision is unavailable at zoom 15
+                if IdentifierPrecision::Metre.resolves_cells(zoom) {
+                    assert_eq!(
+                        generate_hex_identifier_with_precision(
+                            easting,
+                            northing,
+                            zoom,
+                            IdentifierPrecision::Metre
+                        )?
+                        .len(),
+                        IDENTIFIER_LENGTH_METRE
+                    );
+                }
+                assert_eq!(
+                    generate_hex_identifier_unchecked(easting, northing, zoom)?.len(),
+                    IDENTIFIER_LENGTH_UNCHECKED
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_length_mismatch_rejected() {
+        // A metre-length payload carrying the millimetre version byte
+        let mut buf = vec![IDENTIFIER_VERSION, 0, 0, 0, 1, 0, 0, 0, 1, 10];
+        buf.push(buf.iter().fold(0u8, |acc, &b| acc.wrapping_add(b)));
+        assert_eq!(
+            decode_hex_identifier(&URL_SAFE_NO_PAD.encode(&buf)),
+            Err(N3gbError::InvalidIdentifierLength)
+        );
+
+        buf[0] = 9;
+        buf[10] = buf[..10].iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        assert_eq!(
+            decode_hex_identifier(&URL_SAFE_NO_PAD.encode(&buf)),
+            Err(N3gbError::UnsupportedVersion(9))
+        );
+    }
+
+    #[test]
+    fn test_decode_identifiers_matches_serial() -> Result<(), N3gbError> {
+        let mut ids = Vec::new();
+        for i in 0..500 {
+            let easting = 100000.0 + i as f64 * 997.3;
+            let northing = 200000.0 + i as f64 * 1511.7;
+            ids.push(generate_hex_identifier(easting, northing, (i % 16) as u8)?);
+        }
+        ids.push("invalid".to_string());
+        ids.push(generate_hex_identifier_with_precision(
+            457500.0,
+            340000.0,
+            10,
+            IdentifierPrecision::Metre,
+        )?);
+
+        let bulk = decode_identifiers(&ids);
+        assert_eq!(bulk.len(), ids.len());
+        for (id, decoded) in ids.iter().zip(&bulk) {
+            assert_eq!(*decoded, decode_hex_identifier(id));
+        }
+        assert!(decode_identifiers(&[]).is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_identifier() {
         let result = decode_hex_identifier("invalid");
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_non_canonical_identifier_rejected() {
+        let id = generate_hex_identifier(457500.0, 340000.0, 10).unwrap();
+        assert!(decode_hex_identifier(&id).is_ok());
+
+        // The final character carries 4 unused bits; setting one of them keeps the
+        // decoded bytes the same but makes the encoding non-canonical. The
+        // `URL_SAFE_NO_PAD` engine rejects trailing bits and padding, so this
+        // guards against the engine configuration changing.
+        let last = id.chars().last().unwrap();
+        let alphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+        let value = alphabet.find(last).unwrap();
+        let variant_char = alphabet.chars().nth(value | 1).unwrap();
+        let variant = format!("{}{}", &id[..id.len() - 1], variant_char);
+
+        assert_eq!(
+            decode_hex_identifier(&variant),
+            Err(N3gbError::Base64DecodeError)
+        );
+        assert_eq!(
+            decode_hex_identifier(&format!("{}=", id)),
+            Err(N3gbError::Base64DecodeError)
+        );
+    }
+
     #[test]
     fn test_identifier_output() {
-        let id = generate_hex_identifier(457500.0, 340000.0, 10);
+        let id = generate_hex_identifier(457500.0, 340000.0, 10).unwrap();
         println!("Generated identifier: {}", id);
         println!("Length: {} chars", id.len());
 
@@ -2,6 +2,7 @@ use crate::coord::Coordinate;
 use crate::error::N3gbError;
 use crate::index::constants::{
     CELL_RADIUS as RADIUS, CELL_WIDTHS as WIDTHS, GRID_EXTENTS as EXTENTS, MAX_ZOOM_LEVEL,
+    SCALE_FACTOR,
 };
 use geo_types::Point;
 
@@ -20,7 +21,10 @@ use geo_types::Point;
 ///
 /// # Errors
 ///
-/// Returns [`N3gbError::InvalidZoomLevel`] if `z` exceeds `MAX_ZOOM_LEVEL`.
+/// Returns [`N3gbError::InvalidZoomLevel`] if `z` exceeds `MAX_ZOOM_LEVEL`, or
+/// [`N3gbError::OutOfBounds`] if the coordinate is not finite or the computed row
+/// or column lies more than one cell outside the rows and columns spanning
+/// `GRID_EXTENTS` at this zoom level.
 pub fn point_to_row_col<C: Coordinate>(coord: &C, z: u8) -> Result<(i64, i64), N3gbError> {
     if z > MAX_ZOOM_LEVEL {
         return Err(N3gbError::InvalidZoomLevel(z));
@@ -34,12 +38,45 @@ pub fn point_to_row_col<C: Coordinate>(coord: &C, z: u8) -> Result<(i64, i64), N
     let qx = (coord.x() - EXTENTS[0]) / dx;
     let ry = (coord.y() - EXTENTS[1]) / dy;
 
-    let row = ry.round() as i64;
-    let col = (qx - row.rem_euclid(2) as f64).round() as i64;
+    // Validate in floating point before casting, since `as i64` saturates and
+    // NaN would silently become 0.
+    let max_row = ((EXTENTS[3] - EXTENTS[1]) / dy).ceil() + 1.0;
+    let max_col = ((EXTENTS[2] - EXTENTS[0]) / dx).ceil() + 1.0;
+
+    let row_f = ry.round();
+    if !(-1.0..=max_row).contains(&row_f) {
+        return Err(out_of_bounds(coord, z));
+    }
+    let row = row_f as i64;
+
+    let col_f = (qx - row.rem_euclid(2) as f64).round();
+    if !(-1.0..=max_col).contains(&col_f) {
+        return Err(out_of_bounds(coord, z));
+    }
+    let col = col_f as i64;
 
     Ok((row, col))
 }
 
+/// Builds the error for a coordinate outside the plausible grid span.
+///
+/// # Arguments
+///
+/// * `coord` - The offending BNG coordinate.
+/// * `z` - The grid zoom level.
+///
+/// # Returns
+///
+/// An [`N3gbError::OutOfBounds`] describing the coordinate.
+fn out_of_bounds<C: Coordinate>(coord: &C, z: u8) -> N3gbError {
+    N3gbError::OutOfBounds(format!(
+        "({}, {}) is outside the grid at zoom level {}",
+        coord.x(),
+        coord.y(),
+        z
+    ))
+}
+
 /// Converts hex grid row/column indices to a BNG center point.
 ///
 /// Returns the center point of the cell at the given row, column, and zoom level.
@@ -62,15 +99,150 @@ pub fn row_col_to_center(row: i64, col: i64, z: u8) -> Result<Point<f64>, N3gbEr
         return Err(N3gbError::InvalidZoomLevel(z));
     }
 
+    Ok(row_col_to_center_unchecked(row, col, z))
+}
+
+/// Converts hex grid row/column indices to a BNG center point without validating the zoom level.
+///
+/// Infallible counterpart of [`row_col_to_center`] for hot loops where the zoom
+/// level has already been checked. The zoom level is only verified by a
+/// `debug_assert!`; in release builds an out-of-range zoom level panics on the
+/// constant table lookup.
+///
+/// # Arguments
+///
+/// * `row` - The row index of the cell.
+/// * `col` - The column index of the cell.
+/// * `z` - The grid zoom level, which must not exceed `MAX_ZOOM_LEVEL`.
+///
+/// # Returns
+///
+/// The BNG center [`Point<f64>`] of the cell at the given row, column, and zoom level,
+/// rounded to millimetres so that equal addresses always give bitwise-equal centers.
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::{row_col_to_center, row_col_to_center_unchecked};
+///
+/// let center = row_col_to_center_unchecked(3019, 3521, 10);
+/// assert_eq!(Ok(center), row_col_to_center(3019, 3521, 10));
+/// ```
+pub fn row_col_to_center_unchecked(row: i64, col: i64, z: u8) -> Point<f64> {
+    debug_assert!(z <= MAX_ZOOM_LEVEL, "invalid zoom level: {}", z);
+
     let hex_width = WIDTHS[z as usize];
     let r = RADIUS[z as usize];
     let dx = hex_width;
     let dy = 1.5 * r;
 
-    let x = EXTENTS[0] + col as f64 * dx + ((row % 2) as f64 * (dx / 2.0));
+    // `rem_euclid` keeps the parity 0 or 1 for negative rows, so odd rows below
+    // the origin are shifted east like odd rows above it.
+    let x = EXTENTS[0] + col as f64 * dx + (row.rem_euclid(2) as f64 * (dx / 2.0));
     let y = EXTENTS[1] + row as f64 * dy;
 
-    Ok(Point::new(x, y))
+    // Snap to the identifier's millimetre grid so a center decoded from a cell ID
+    // is bitwise equal to one computed from (row, col).
+    let snap = |v: f64| (v * SCALE_FACTOR as f64).round() / SCALE_FACTOR as f64;
+    Point::new(snap(x), snap(y))
+}
+
+/// Finds the cell whose center is nearest a point, one axis at a time.
+///
+/// Unlike [`point_to_row_col`], which assigns points to cells by rectangle, this
+/// rounds to the nearest row and then to the nearest center in that row. It
+/// recovers the exact address of a center that has been moved by less than half
+/// the row spacing north or south and half the cell width east or west, e.g. by
+/// rounding a center to whole metres.
+///
+/// # Arguments
+///
+/// * `x` - The easting of the (approximate) center.
+/// * `y` - The northing of the (approximate) center.
+/// * `z` - The grid zoom level.
+///
+/// # Returns
+///
+/// The `(row, col)` of the nearest lattice center.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidZoomLevel`] if `z` exceeds `MAX_ZOOM_LEVEL`, or
+/// [`N3gbError::OutOfBounds`] if the point is not finite.
+pub(crate) fn center_to_row_col(x: f64, y: f64, z: u8) -> Result<(i64, i64), N3gbError> {
+    if z > MAX_ZOOM_LEVEL {
+        return Err(N3gbError::InvalidZoomLevel(z));
+    }
+    if !x.is_finite() || !y.is_finite() {
+        return Err(out_of_bounds(&(x, y), z));
+    }
+
+    let dx = WIDTHS[z as usize];
+    let dy = 1.5 * RADIUS[z as usize];
+
+    let row = ((y - EXTENTS[1]) / dy).round() as i64;
+    let col = ((x - EXTENTS[0]) / dx - row.rem_euclid(2) as f64 / 2.0).round() as i64;
+    Ok((row, col))
+}
+
+/// Number of bits each of the row and column occupies in a packed key.
+const PACKED_AXIS_BITS: u32 = 30;
+
+/// Packs a zoom level, row and column into a single `u64` key.
+///
+/// The layout, from most to least significant bit, is 4 bits of zoom level,
+/// 30 bits of `row + 1` and 30 bits of `col + 1`. The offset of one lets the
+/// `-1` row and column that [`point_to_row_col`] can return at the grid's
+/// lower edge be stored unsigned. Every row and column at zoom 15 fits with
+/// room to spare.
+///
+/// # Arguments
+///
+/// * `zoom_level` - The zoom level (0-15).
+/// * `row` - The row index, from `-1`.
+/// * `col` - The column index, from `-1`.
+///
+/// # Returns
+///
+/// The packed key.
+pub(crate) fn pack_key(zoom_level: u8, row: i64, col: i64) -> u64 {
+    let mask = (1u64 << PACKED_AXIS_BITS) - 1;
+    debug_assert!(zoom_level <= MAX_ZOOM_LEVEL);
+    debug_assert!((-1..mask as i64).contains(&row) && (-1..mask as i64).contains(&col));
+
+    ((zoom_level as u64) << (2 * PACKED_AXIS_BITS))
+        | (((row + 1) as u64 & mask) << PACKED_AXIS_BITS)
+        | ((col + 1) as u64 & mask)
+}
+
+/// Unpacks a key produced by [`HexCell::packed_key`](crate::HexCell::packed_key).
+///
+/// See [`HexCell::packed_key`](crate::HexCell::packed_key) for the bit layout.
+///
+/// # Arguments
+///
+/// * `key` - The packed key.
+///
+/// # Returns
+///
+/// The `(zoom_level, row, col)` stored in the key.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{HexCell, unpack_key};
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let cell = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+/// assert_eq!(unpack_key(cell.packed_key()), (12, cell.row, cell.col));
+/// # Ok(())
+/// # }
+/// ```
+pub fn unpack_key(key: u64) -> (u8, i64, i64) {
+    let mask = (1u64 << PACKED_AXIS_BITS) - 1;
+    let zoom_level = (key >> (2 * PACKED_AXIS_BITS)) as u8;
+    let row = ((key >> PACKED_AXIS_BITS) & mask) as i64 - 1;
+    let col = (key & mask) as i64 - 1;
+    (zoom_level, row, col)
 }
 
 /// Converts odd-r offset (row, col) to cube coordinates (q, r, s).
@@ -84,12 +256,69 @@ pub fn row_col_to_center(row: i64, col: i64, z: u8) -> Result<Point<f64>, N3gbEr
 ///
 /// A `(q, r, s)` tuple of cube coordinates equivalent to the given offset coordinates.
 pub(crate) fn offset_to_cube(row: i64, col: i64) -> (i64, i64, i64) {
-    let q = col - row / 2;
+    let q = col - row.div_euclid(2);
     let r = row;
     let s = -q - r;
     (q, r, s)
 }
 
+/// One of the six directions from a cell to a neighbour.
+///
+/// The grid is pointy-top, so neighbours lie due east and west and on the four
+/// diagonals; there are no due north or south neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    /// East: same row, next column.
+    E,
+    /// North-east: the row above, shifted half a cell east.
+    NE,
+    /// North-west: the row above, shifted half a cell west.
+    NW,
+    /// West: same row, previous column.
+    W,
+    /// South-west: the row below, shifted half a cell west.
+    SW,
+    /// South-east: the row below, shifted half a cell east.
+    SE,
+}
+
+/// Returns the odd-r offset `(row, col)` addresses of the six neighbours of a cell.
+///
+/// Odd rows are shifted half a cell east, so the diagonal neighbours depend on
+/// the parity of `row`.
+///
+/// # Arguments
+///
+/// * `row` - The row index in odd-r offset coordinates.
+/// * `col` - The column index in odd-r offset coordinates.
+///
+/// # Returns
+///
+/// The six neighbouring `(row, col)` pairs, ordered E, NE, NW, W, SW, SE to
+/// match [`HexDirection`], so `neighbor_row_cols(row, col)[dir as usize]` is the
+/// neighbour in direction `dir`.
+pub(crate) fn neighbor_row_cols(row: i64, col: i64) -> [(i64, i64); 6] {
+    if row.rem_euclid(2) == 0 {
+        [
+            (row, col + 1),
+            (row + 1, col),
+            (row + 1, col - 1),
+            (row, col - 1),
+            (row - 1, col - 1),
+            (row - 1, col),
+        ]
+    } else {
+        [
+            (row, col + 1),
+            (row + 1, col + 1),
+            (row + 1, col),
+            (row, col - 1),
+            (row - 1, col),
+            (row - 1, col + 1),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,9 +357,82 @@ mod tests {
         assert!(matches!(result, Err(N3gbError::InvalidZoomLevel(20))));
     }
 
+    #[test]
+    fn test_point_to_row_col_out_of_bounds() -> Result<(), N3gbError> {
+        for coord in [
+            (1.0e18, 340000.0),
+            (457500.0, -1.0e18),
+            (-5.0e6, -5.0e6),
+            (f64::NAN, 340000.0),
+            (457500.0, f64::INFINITY),
+        ] {
+            for z in [0, 10, MAX_ZOOM_LEVEL] {
+                assert!(matches!(
+                    point_to_row_col(&coord, z),
+                    Err(N3gbError::OutOfBounds(_))
+                ));
+            }
+        }
+
+        // Corners of the grid extents are still accepted at every zoom level.
+        for z in 0..=MAX_ZOOM_LEVEL {
+            point_to_row_col(&(EXTENTS[0], EXTENTS[1]), z)?;
+            point_to_row_col(&(EXTENTS[2], EXTENTS[3]), z)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbor_row_cols_are_one_step_away() {
+        let near_origin = (-3..=3).flat_map(|row| (-1..=1).map(move |col| (row, col)));
+        for (row, col) in [(10, 10), (11, 10)].into_iter().chain(near_origin) {
+            let (q, r, s) = offset_to_cube(row, col);
+            for (n_row, n_col) in neighbor_row_cols(row, col) {
+                let (nq, nr, ns) = offset_to_cube(n_row, n_col);
+                let dist = ((q - nq).abs() + (r - nr).abs() + (s - ns).abs()) / 2;
+                assert_eq!(dist, 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_row_parity_near_origin() -> Result<(), N3gbError> {
+        // Zoom 15 cells are 1m wide, so odd rows sit half a metre east
+        let x_at = |row| row_col_to_center(row, 0, MAX_ZOOM_LEVEL).map(|c| c.x());
+        assert_eq!(x_at(0)?, 0.0);
+        assert_eq!(x_at(1)?, 0.5);
+        assert_eq!(x_at(-1)?, 0.5);
+        assert_eq!(x_at(-2)?, 0.0);
+        assert_eq!(x_at(-3)?, 0.5);
+
+        for z in [0, 10, MAX_ZOOM_LEVEL] {
+            let dx = WIDTHS[z as usize];
+            let below = row_col_to_center(-1, 0, z)?;
+            let origin = row_col_to_center(0, 0, z)?;
+            let above = row_col_to_center(1, 0, z)?;
+            assert_eq!(below.x(), above.x());
+            // Centers are snapped to the nearest millimetre
+            assert!((below.x() - origin.x() - dx / 2.0).abs() <= 5e-4);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_row_col_to_center_invalid_zoom() {
         let result = row_col_to_center(100, 100, 16);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_row_col_to_center_unchecked_matches_checked() -> Result<(), N3gbError> {
+        for z in 0..=MAX_ZOOM_LEVEL {
+            for (row, col) in [(0, 0), (1, 0), (3019, 3521), (-3, 7), (12345, 678)] {
+                assert_eq!(
+                    row_col_to_center_unchecked(row, col, z),
+                    row_col_to_center(row, col, z)?
+                );
+            }
+        }
+        Ok(())
+    }
 }
@@ -2,7 +2,18 @@ pub mod constants;
 mod identifier;
 mod indexing;
 
-pub use constants::{CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, IDENTIFIER_VERSION, MAX_ZOOM_LEVEL};
-pub use identifier::{decode_hex_identifier, generate_hex_identifier};
-pub(crate) use indexing::offset_to_cube;
-pub use indexing::{point_to_row_col, row_col_to_center};
+pub use constants::{
+    CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, IDENTIFIER_LENGTH, IDENTIFIER_LENGTH_METRE,
+    IDENTIFIER_LENGTH_UNCHECKED, IDENTIFIER_VERSION, IDENTIFIER_VERSION_METRE,
+    IDENTIFIER_VERSION_UNCHECKED, MAX_GRID_CELLS, MAX_ZOOM_LEVEL, ZoomInfo, suggest_zoom_for_line,
+    zoom_table,
+};
+pub use identifier::{
+    IdentifierPrecision, decode_hex_identifier, decode_identifiers, generate_hex_identifier,
+    generate_hex_identifier_unchecked, generate_hex_identifier_with_precision,
+};
+pub(crate) use identifier::{grid_cell_identifier, scale_coordinate};
+pub use indexing::{
+    HexDirection, point_to_row_col, row_col_to_center, row_col_to_center_unchecked, unpack_key,
+};
+pub(crate) use indexing::{center_to_row_col, neighbor_row_cols, offset_to_cube, pack_key};
@@ -1,12 +1,14 @@
 use crate::cell::HexCell;
 use crate::error::N3gbError;
-use arrow_array::{Float64Array, Int64Array, RecordBatch, StringArray, UInt8Array};
+use crate::parallel::*;
+use arrow_array::{
+    Array, ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray, UInt8Array,
+};
 use arrow_schema::{DataType, Field, Schema};
 use geoarrow_array::IntoArrow;
 use geoarrow_array::array::{PointArray, PolygonArray};
 use geoarrow_array::builder::{PointBuilder, PolygonBuilder};
 use geoarrow_schema::{Crs, Dimension, Metadata, PointType, PolygonType};
-use rayon::prelude::*;
 use std::sync::Arc;
 
 /// Builds the geoarrow metadata describing the British National Grid CRS (EPSG:27700).
@@ -19,6 +21,152 @@ fn bng_metadata() -> Arc<Metadata> {
     Arc::new(Metadata::new(crs, None))
 }
 
+/// Selects which columns [`HexCellsToArrow::to_record_batch_fields`] includes.
+///
+/// [`RecordBatchFields::all`] (the default) matches [`HexCellsToArrow::to_record_batch`];
+/// start from [`RecordBatchFields::none`] to opt in to individual columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordBatchFields {
+    pub id: bool,
+    pub zoom_level: bool,
+    pub row: bool,
+    pub col: bool,
+    pub easting: bool,
+    pub northing: bool,
+    pub geometry: bool,
+}
+
+impl Default for RecordBatchFields {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl RecordBatchFields {
+    /// Select every column.
+    ///
+    /// # Returns
+    /// A [`RecordBatchFields`] with all columns enabled.
+    pub fn all() -> Self {
+        Self {
+            id: true,
+            zoom_level: true,
+            row: true,
+            col: true,
+            easting: true,
+            northing: true,
+            geometry: true,
+        }
+    }
+
+    /// Select no columns.
+    ///
+    /// # Returns
+    /// A [`RecordBatchFields`] with all columns disabled.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::RecordBatchFields;
+    ///
+    /// let fields = RecordBatchFields::none().id(true).geometry(true);
+    /// ```
+    pub fn none() -> Self {
+        Self {
+            id: false,
+            zoom_level: false,
+            row: false,
+            col: false,
+            easting: false,
+            northing: false,
+            geometry: false,
+        }
+    }
+
+    /// Include or exclude the `id` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn id(mut self, include: bool) -> Self {
+        self.id = include;
+        self
+    }
+
+    /// Include or exclude the `zoom_level` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn zoom_level(mut self, include: bool) -> Self {
+        self.zoom_level = include;
+        self
+    }
+
+    /// Include or exclude the `row` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn row(mut self, include: bool) -> Self {
+        self.row = include;
+        self
+    }
+
+    /// Include or exclude the `col` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn col(mut self, include: bool) -> Self {
+        self.col = include;
+        self
+    }
+
+    /// Include or exclude the `easting` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn easting(mut self, include: bool) -> Self {
+        self.easting = include;
+        self
+    }
+
+    /// Include or exclude the `northing` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn northing(mut self, include: bool) -> Self {
+        self.northing = include;
+        self
+    }
+
+    /// Include or exclude the `geometry` column.
+    ///
+    /// # Arguments
+    /// * `include` - Whether to include the column.
+    ///
+    /// # Returns
+    /// The updated selection for chaining.
+    pub fn geometry(mut self, include: bool) -> Self {
+        self.geometry = include;
+        self
+    }
+}
+
 /// Trait for converting collections of [`HexCell`]s to Arrow arrays.
 ///
 /// Implemented for any type that dereferences to `[HexCell]` (e.g. `Vec<HexCell>`, `&[HexCell]`).
@@ -47,6 +195,43 @@ pub trait HexCellsToArrow {
     /// Returns [`N3gbError::IoError`] if the columns cannot be assembled into a valid
     /// [`RecordBatch`] (via `From<ArrowError>`).
     fn to_record_batch(&self) -> Result<RecordBatch, N3gbError>;
+    /// Converts cells to a RecordBatch containing only the selected columns.
+    ///
+    /// Columns keep the same order as [`HexCellsToArrow::to_record_batch`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The columns to include.
+    ///
+    /// # Returns
+    ///
+    /// A [`RecordBatch`] with the selected columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the columns cannot be assembled into a valid
+    /// [`RecordBatch`] (via `From<ArrowError>`), e.g. if no columns are selected.
+    fn to_record_batch_fields(&self, fields: &RecordBatchFields) -> Result<RecordBatch, N3gbError>;
+    /// Converts cells to a RecordBatch with caller-provided columns appended.
+    ///
+    /// The extra columns follow the standard columns from
+    /// [`HexCellsToArrow::to_record_batch`], in the order given, with one row per cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `extra` - `(name, array)` pairs to append; each array must have one value per cell.
+    ///
+    /// # Returns
+    ///
+    /// A [`RecordBatch`] with the standard cell columns followed by the `extra` columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::InvalidArgument`] if an extra column's name is already taken
+    /// by a standard column or an earlier extra column, or [`N3gbError::IoError`] if an
+    /// extra column's length differs from the number of cells, or if the columns cannot
+    /// be assembled into a valid [`RecordBatch`].
+    fn to_record_batch_with(&self, extra: &[(&str, ArrayRef)]) -> Result<RecordBatch, N3gbError>;
 }
 
 impl<T: AsRef<[HexCell]>> HexCellsToArrow for T {
@@ -69,39 +254,85 @@ impl<T: AsRef<[HexCell]>> HexCellsToArrow for T {
     }
 
     fn to_record_batch(&self) -> Result<RecordBatch, N3gbError> {
+        self.to_record_batch_fields(&RecordBatchFields::all())
+    }
+
+    fn to_record_batch_fields(&self, fields: &RecordBatchFields) -> Result<RecordBatch, N3gbError> {
         let cells = self.as_ref();
-        let polygon_array = self.to_arrow_polygons();
-        let ids: StringArray = cells.iter().map(|c| Some(c.id.as_str())).collect();
-        let zoom_levels: UInt8Array = cells.iter().map(|c| Some(c.zoom_level)).collect();
-        let rows: Int64Array = cells.iter().map(|c| Some(c.row)).collect();
-        let cols: Int64Array = cells.iter().map(|c| Some(c.col)).collect();
-        let eastings: Float64Array = cells.iter().map(|c| Some(c.easting())).collect();
-        let northings: Float64Array = cells.iter().map(|c| Some(c.northing())).collect();
-
-        let geometry_field = polygon_array.extension_type().to_field("geometry", false);
-        let schema = Schema::new(vec![
-            Field::new("id", DataType::Utf8, false),
-            Field::new("zoom_level", DataType::UInt8, false),
-            Field::new("row", DataType::Int64, false),
-            Field::new("col", DataType::Int64, false),
-            Field::new("easting", DataType::Float64, false),
-            Field::new("northing", DataType::Float64, false),
-            geometry_field,
-        ]);
-
-        RecordBatch::try_new(
-            Arc::new(schema),
-            vec![
-                Arc::new(ids),
-                Arc::new(zoom_levels),
-                Arc::new(rows),
-                Arc::new(cols),
-                Arc::new(eastings),
-                Arc::new(northings),
-                Arc::new(polygon_array.into_arrow()),
-            ],
-        )
-        .map_err(N3gbError::from)
+        let mut schema_fields: Vec<Field> = Vec::new();
+        let mut columns: Vec<ArrayRef> = Vec::new();
+
+        if fields.id {
+            let ids: StringArray = cells.iter().map(|c| Some(c.id.as_str())).collect();
+            schema_fields.push(Field::new("id", DataType::Utf8, false));
+            columns.push(Arc::new(ids));
+        }
+        if fields.zoom_level {
+            let zoom_levels: UInt8Array = cells.iter().map(|c| Some(c.zoom_level)).collect();
+            schema_fields.push(Field::new("zoom_level", DataType::UInt8, false));
+            columns.push(Arc::new(zoom_levels));
+        }
+        if fields.row {
+            let rows: Int64Array = cells.iter().map(|c| Some(c.row)).collect();
+            schema_fields.push(Field::new("row", DataType::Int64, false));
+            columns.push(Arc::new(rows));
+        }
+        if fields.col {
+            let cols: Int64Array = cells.iter().map(|c| Some(c.col)).collect();
+            schema_fields.push(Field::new("col", DataType::Int64, false));
+            columns.push(Arc::new(cols));
+        }
+        if fields.easting {
+            let eastings: Float64Array = cells.iter().map(|c| Some(c.easting())).collect();
+            schema_fields.push(Field::new("easting", DataType::Float64, false));
+            columns.push(Arc::new(eastings));
+        }
+        if fields.northing {
+            let northings: Float64Array = cells.iter().map(|c| Some(c.northing())).collect();
+            schema_fields.push(Field::new("northing", DataType::Float64, false));
+            columns.push(Arc::new(northings));
+        }
+        if fields.geometry {
+            let polygon_array = self.to_arrow_polygons();
+            schema_fields.push(polygon_array.extension_type().to_field("geometry", false));
+            columns.push(Arc::new(polygon_array.into_arrow()));
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(schema_fields)), columns).map_err(N3gbError::from)
+    }
+
+    fn to_record_batch_with(&self, extra: &[(&str, ArrayRef)]) -> Result<RecordBatch, N3gbError> {
+        let batch = self.to_record_batch()?;
+        let num_rows = batch.num_rows();
+
+        let mut fields: Vec<Field> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.as_ref().clone())
+            .collect();
+        let mut columns = batch.columns().to_vec();
+
+        for (name, array) in extra {
+            if fields.iter().any(|field| field.name() == name) {
+                return Err(N3gbError::InvalidArgument(format!(
+                    "Column '{}' already exists",
+                    name
+                )));
+            }
+            if array.len() != num_rows {
+                return Err(N3gbError::IoError(format!(
+                    "Column '{}' has {} rows, expected {}",
+                    name,
+                    array.len(),
+                    num_rows
+                )));
+            }
+            fields.push(Field::new(*name, array.data_type().clone(), true));
+            columns.push(Arc::clone(array));
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(N3gbError::from)
     }
 }
 
@@ -149,4 +380,85 @@ mod tests {
         assert_eq!(polygon_array.len(), 3);
         Ok(())
     }
+
+    #[test]
+    fn test_record_batch_with_extra_column() -> Result<(), N3gbError> {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+        let scores: ArrayRef = Arc::new(Float64Array::from(vec![0.25, 0.75]));
+
+        let batch = cells.to_record_batch_with(&[("score", Arc::clone(&scores))])?;
+        assert_eq!(batch.num_columns(), 8);
+        assert_eq!(batch.schema().field(7).name(), "score");
+
+        let dir = tempfile::tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let path = dir.path().join("scores.parquet");
+        crate::io::parquet::write_geoparquet(&batch, &path)?;
+
+        let file = std::fs::File::open(&path)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        let read_back: Vec<f64> = reader
+            .map(|batch| {
+                let batch = batch?;
+                let column = batch.column_by_name("score").expect("score column");
+                let values = column
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .expect("Float64 score column");
+                Ok(values.values().to_vec())
+            })
+            .collect::<Result<Vec<_>, N3gbError>>()?
+            .concat();
+        assert_eq!(read_back, vec![0.25, 0.75]);
+
+        let short: ArrayRef = Arc::new(Float64Array::from(vec![1.0]));
+        assert!(matches!(
+            cells.to_record_batch_with(&[("score", short)]),
+            Err(N3gbError::IoError(_))
+        ));
+
+        // Extra columns may not reuse a standard or earlier extra column's name
+        assert!(matches!(
+            cells.to_record_batch_with(&[("id", Arc::clone(&scores))]),
+            Err(N3gbError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            cells.to_record_batch_with(&[("score", Arc::clone(&scores)), ("score", scores)]),
+            Err(N3gbError::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_batch_selected_fields() -> Result<(), N3gbError> {
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+
+        let fields = RecordBatchFields::none().id(true).geometry(true);
+        let batch = cells.to_record_batch_fields(&fields)?;
+        let names: Vec<&str> = batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+        assert_eq!(names, vec!["id", "geometry"]);
+        assert_eq!(batch.num_rows(), 2);
+
+        let full = cells.to_record_batch_fields(&RecordBatchFields::default())?;
+        assert_eq!(full.schema(), cells.to_record_batch()?.schema());
+
+        assert!(
+            cells
+                .to_record_batch_fields(&RecordBatchFields::none())
+                .is_err()
+        );
+        Ok(())
+    }
 }
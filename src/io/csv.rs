@@ -2,12 +2,22 @@ use crate::cell::HexCell;
 use crate::coord::{ConversionMethod, Crs};
 use crate::error::N3gbError;
 use crate::geom::parse_geometry;
+use crate::io::arrow::HexCellsToArrow;
+use crate::io::parquet::GeoParquetBatchWriter;
+use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt64Array};
+use encoding_rs_io::{DecodeReaderBytes, DecodeReaderBytesBuilder};
+use geo_types::{Coord, MultiPolygon};
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::path::Path;
+use std::sync::Arc;
+
+/// A CSV reader over an input file transcoded to UTF-8.
+type CsvReader = csv::Reader<DecodeReaderBytes<File, Vec<u8>>>;
 
 enum SourceIndices {
     Geometry(usize),
+    GeometryColumns(Vec<usize>),
     Coordinates { x_idx: usize, y_idx: usize },
 }
 
@@ -17,12 +27,53 @@ pub enum GeometryFormat {
     Wkt,
     /// GeoJSON format
     GeoJson,
+    /// Well-Known Text `MULTIPOLYGON` of all cells from an input row, written as
+    /// a single output row
+    WktMultiPolygon,
+    /// GeoJSON `MultiPolygon` of all cells from an input row, written as a single
+    /// output row
+    GeoJsonMultiPolygon,
+}
+
+impl GeometryFormat {
+    /// Whether this format aggregates an input row's cells into one output row.
+    fn is_multi(self) -> bool {
+        matches!(self, Self::WktMultiPolygon | Self::GeoJsonMultiPolygon)
+    }
+}
+
+/// Character encoding of an input CSV file.
+///
+/// Input is transcoded to UTF-8 before parsing, and output is always UTF-8. A
+/// UTF-8 byte order mark at the start of the file takes precedence over the
+/// configured encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvEncoding {
+    /// UTF-8, read without transcoding (the default)
+    #[default]
+    Utf8,
+    /// Windows-1252, the superset of Latin-1 (ISO-8859-1) written by many
+    /// Windows tools
+    Windows1252,
+}
+
+impl CsvEncoding {
+    /// The `encoding_rs` encoding to transcode from, or `None` to pass bytes through.
+    fn encoding(self) -> Option<&'static encoding_rs::Encoding> {
+        match self {
+            CsvEncoding::Utf8 => None,
+            CsvEncoding::Windows1252 => Some(encoding_rs::WINDOWS_1252),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum CoordinateSource {
     /// A single column containing WKT or GeoJSON geometry
     GeometryColumn(String),
+    /// Several WKT or GeoJSON geometry columns, tried in order per row; the first
+    /// non-empty value is used
+    GeometryColumns(Vec<String>),
     /// Separate X and Y coordinate columns (e.g., Easting/Northing or Lon/Lat)
     CoordinateColumns { x_column: String, y_column: String },
 }
@@ -35,8 +86,16 @@ pub struct CsvHexConfig {
     pub zoom_level: u8,
     pub crs: Crs,
     pub include_hex_geometry: Option<GeometryFormat>,
+    pub geometry_precision: Option<u8>,
     pub hex_density: bool,
     pub conversion_method: ConversionMethod,
+    pub id_column_name: String,
+    pub geometry_column_name: String,
+    pub dedup: bool,
+    pub flexible: bool,
+    pub skip_errors: bool,
+    pub keep_source_columns: bool,
+    pub encoding: CsvEncoding,
 }
 
 impl CsvHexConfig {
@@ -62,8 +121,16 @@ impl CsvHexConfig {
             zoom_level,
             crs: Crs::default(),
             include_hex_geometry: None,
+            geometry_precision: None,
             hex_density: false,
             conversion_method: ConversionMethod::default(),
+            id_column_name: "hex_id".to_string(),
+            geometry_column_name: "hex_geometry".to_string(),
+            dedup: false,
+            flexible: false,
+            skip_errors: false,
+            keep_source_columns: false,
+            encoding: CsvEncoding::default(),
         }
     }
 
@@ -103,8 +170,48 @@ impl CsvHexConfig {
             zoom_level,
             crs: Crs::default(),
             include_hex_geometry: None,
+            geometry_precision: None,
             hex_density: false,
             conversion_method: ConversionMethod::default(),
+            id_column_name: "hex_id".to_string(),
+            geometry_column_name: "hex_geometry".to_string(),
+            dedup: false,
+            flexible: false,
+            skip_errors: false,
+            keep_source_columns: false,
+            encoding: CsvEncoding::default(),
+        }
+    }
+
+    /// Create config for a CSV whose rows populate one of several geometry columns.
+    ///
+    /// Each row uses the first of the columns, in the given order, that holds a
+    /// non-empty value. All of the columns are treated as source columns.
+    ///
+    /// # Arguments
+    /// * `geometry_columns` - Names of the CSV columns containing WKT or GeoJSON geometry.
+    /// * `zoom_level` - Hex zoom level to encode cells at.
+    ///
+    /// # Returns
+    /// A new [`CsvHexConfig`] using the given geometry columns as its coordinate source.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::{CsvHexConfig, Crs};
+    ///
+    /// let config = CsvHexConfig::from_geometry_columns(["Geo Point", "Geo Shape"], 12)
+    ///     .crs(Crs::Wgs84);
+    /// ```
+    pub fn from_geometry_columns<I, S>(geometry_columns: I, zoom_level: u8) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            source: CoordinateSource::GeometryColumns(
+                geometry_columns.into_iter().map(Into::into).collect(),
+            ),
+            ..Self::new(String::new(), zoom_level)
         }
     }
 
@@ -134,6 +241,11 @@ impl CsvHexConfig {
 
     /// Include hex polygon geometry in output.
     ///
+    /// With [`GeometryFormat::WktMultiPolygon`] or [`GeometryFormat::GeoJsonMultiPolygon`],
+    /// [`csv_to_hex_csv`] writes one output row per input row instead of one per cell: the
+    /// geometry column holds a multipolygon of all the row's cells and the ID column lists
+    /// their IDs separated by `;`. Density output always has one row per cell.
+    ///
     /// # Arguments
     /// * `format` - The [`GeometryFormat`] (WKT or GeoJSON) for the emitted hex geometry.
     ///
@@ -144,6 +256,23 @@ impl CsvHexConfig {
         self
     }
 
+    /// Round emitted hex geometry coordinates to a number of decimal places.
+    ///
+    /// By default coordinates are written at full `f64` precision, which makes
+    /// geometry columns large. BNG coordinates are in metres, so `3` keeps
+    /// millimetres and `0` whole metres. Has no effect unless hex geometry is
+    /// included with [`CsvHexConfig::with_hex_geometry`].
+    ///
+    /// # Arguments
+    /// * `decimals` - Number of decimal places to keep in each coordinate.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn geometry_precision(mut self, decimals: u8) -> Self {
+        self.geometry_precision = Some(decimals);
+        self
+    }
+
     /// Sets the WGS84→BNG conversion backend.
     ///
     /// Only relevant when `crs` is [`Crs::Wgs84`]. Defaults to [`ConversionMethod::Ostn15`].
@@ -158,9 +287,125 @@ impl CsvHexConfig {
         self
     }
 
+    /// Set the name of the output column holding the hex ID.
+    ///
+    /// Defaults to `hex_id`. Useful when the input already has a column by that name.
+    ///
+    /// # Arguments
+    /// * `name` - The header to write for the hex ID column.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn id_column_name(mut self, name: impl Into<String>) -> Self {
+        self.id_column_name = name.into();
+        self
+    }
+
+    /// Set the name of the output column holding the hex geometry.
+    ///
+    /// Defaults to `hex_geometry`. Only written when [`CsvHexConfig::with_hex_geometry`]
+    /// is set.
+    ///
+    /// # Arguments
+    /// * `name` - The header to write for the hex geometry column.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn geometry_column_name(mut self, name: impl Into<String>) -> Self {
+        self.geometry_column_name = name.into();
+        self
+    }
+
+    /// Skip output rows whose hex ID has already been written.
+    ///
+    /// Overlapping input features (e.g. lines sharing a segment) otherwise emit the
+    /// same cell more than once. Each input row still keeps the attributes of the
+    /// first row that produced a given cell.
+    ///
+    /// Note this keeps every emitted hex ID in memory for the duration of the
+    /// conversion, so memory grows with the number of distinct cells rather than
+    /// staying flat as in the default streaming mode. Has no effect with
+    /// [`CsvHexConfig::hex_density`], which already aggregates per cell.
+    ///
+    /// # Arguments
+    /// * `dedup` - Whether to drop rows for hex IDs that were already written.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Accept records whose field count differs from the header row.
+    ///
+    /// Maps to [`csv::ReaderBuilder::flexible`]. Short records are padded with empty
+    /// values in the output so every row matches the output header. A record that is
+    /// missing its geometry or coordinate columns is skipped or reported as an error
+    /// depending on [`CsvHexConfig::skip_errors`].
+    ///
+    /// # Arguments
+    /// * `flexible` - Whether to allow records with a differing number of fields.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    /// Skip unreadable records instead of failing the whole conversion.
+    ///
+    /// When set, records that cannot be read, are missing their geometry or
+    /// coordinate columns, or hold a value that fails to parse are dropped from the
+    /// output. When unset (the default) the first such record returns an error.
+    ///
+    /// # Arguments
+    /// * `skip_errors` - Whether to skip bad records rather than returning an error.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn skip_errors(mut self, skip_errors: bool) -> Self {
+        self.skip_errors = skip_errors;
+        self
+    }
+
+    /// Pass the geometry or coordinate source columns through to the output.
+    ///
+    /// By default the source columns are left out, since the hex ID replaces them.
+    /// Keeping them helps trace each output row back to its input location.
+    /// Columns listed in [`CsvHexConfig::exclude`] are still left out.
+    ///
+    /// # Arguments
+    /// * `keep` - Whether to write the source columns to the output.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn keep_source_columns(mut self, keep: bool) -> Self {
+        self.keep_source_columns = keep;
+        self
+    }
+
+    /// Set the character encoding of the input file.
+    ///
+    /// The `csv` crate only reads UTF-8, so non-ASCII text in files saved by older
+    /// Windows tools (e.g. accented place names) fails to read. Setting
+    /// [`CsvEncoding::Windows1252`] transcodes such files to UTF-8 as they are read.
+    ///
+    /// # Arguments
+    /// * `encoding` - The [`CsvEncoding`] of the input file.
+    ///
+    /// # Returns
+    /// The updated config for chaining.
+    pub fn encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     /// Aggregate output to one row per hex cell with a count of input rows.
     ///
-    /// Output columns: `hex_id`, `count` (and optionally `hex_geometry`).
+    /// Output columns: `hex_id`, `count` (and optionally `hex_geometry`), using the
+    /// configured ID and geometry column names.
     /// Input attribute columns are dropped since rows are aggregated.
     ///
     /// # Returns
@@ -171,6 +416,33 @@ impl CsvHexConfig {
     }
 }
 
+/// Open an input CSV file, transcoding it to UTF-8 from the configured encoding.
+///
+/// # Arguments
+/// * `csv_path` - Path of the input CSV file.
+/// * `config` - Conversion configuration (encoding, flexible record lengths).
+///
+/// # Returns
+/// A CSV reader positioned before the header row.
+///
+/// # Errors
+/// Returns [`N3gbError::IoError`] if the file cannot be opened.
+fn open_csv_reader(
+    csv_path: impl AsRef<Path>,
+    config: &CsvHexConfig,
+) -> Result<CsvReader, N3gbError> {
+    let file = File::open(csv_path)?;
+    let decoder = DecodeReaderBytesBuilder::new()
+        .encoding(config.encoding.encoding())
+        .bom_override(true)
+        .utf8_passthru(true)
+        .strip_bom(true)
+        .build(file);
+    Ok(csv::ReaderBuilder::new()
+        .flexible(config.flexible)
+        .from_reader(decoder))
+}
+
 /// Convert a single CSV record into the hex cells it covers.
 ///
 /// # Arguments
@@ -196,17 +468,15 @@ fn read_cells_from_record(
             let geom_str = record.get(*idx).ok_or_else(|| {
                 N3gbError::CsvError(format!("Missing geometry column at index {}", idx))
             })?;
-            let geom = parse_geometry(geom_str)?;
-            match HexCell::from_geometry(
-                geom,
-                config.zoom_level,
-                config.crs,
-                config.conversion_method,
-            ) {
-                Ok(cells) => Ok(cells),
-                Err(N3gbError::ProjectionError(_)) => Ok(vec![]),
-                Err(e) => Err(e),
-            }
+            read_cells_from_geometry(geom_str, config)
+        }
+        SourceIndices::GeometryColumns(indices) => {
+            let geom_str = indices
+                .iter()
+                .filter_map(|&idx| record.get(idx))
+                .find(|value| !value.trim().is_empty())
+                .ok_or_else(|| N3gbError::CsvError("All geometry columns are empty".to_string()))?;
+            read_cells_from_geometry(geom_str, config)
         }
         SourceIndices::Coordinates { x_idx, y_idx } => {
             let x_str = record
@@ -239,6 +509,192 @@ fn read_cells_from_record(
     }
 }
 
+/// Parse a WKT or GeoJSON value and convert it into the hex cells it covers.
+///
+/// # Arguments
+/// * `geom_str` - The geometry value read from a record.
+/// * `config` - Conversion configuration (zoom level, CRS, conversion method).
+///
+/// # Returns
+/// The hex cells covered by the geometry, or an empty vector if it falls outside
+/// the projectable area.
+///
+/// # Errors
+/// Returns [`N3gbError::GeometryParseError`] if the value cannot be parsed, and
+/// [`N3gbError::InvalidZoomLevel`] if the configured zoom level is invalid.
+fn read_cells_from_geometry(
+    geom_str: &str,
+    config: &CsvHexConfig,
+) -> Result<Vec<HexCell>, N3gbError> {
+    let geom = parse_geometry(geom_str)?;
+    match HexCell::from_geometry(
+        geom,
+        config.zoom_level,
+        config.crs,
+        config.conversion_method,
+    ) {
+        Ok(cells) => Ok(cells),
+        Err(N3gbError::ProjectionError(_)) => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+/// Resolve the configured source columns against the CSV header.
+///
+/// # Arguments
+/// * `headers` - The header record of the input CSV.
+/// * `config` - Conversion configuration describing the source columns.
+///
+/// # Returns
+/// The resolved source column indices, and the set of column indices to leave out
+/// of the output (the source columns, unless `keep_source_columns` is set, plus any
+/// configured exclusions).
+///
+/// # Errors
+/// Returns [`N3gbError::CsvError`] if a configured column name is empty or not found.
+fn resolve_source_indices(
+    headers: &csv::StringRecord,
+    config: &CsvHexConfig,
+) -> Result<(SourceIndices, HashSet<usize>), N3gbError> {
+    // Determine which columns to exclude based on source type
+    // Best practice is to always exclude ANY geometry column
+    let (source_indices, mut exclude) =
+        match &config.source {
+            CoordinateSource::GeometryColumn(col) => {
+                if col.is_empty() {
+                    return Err(N3gbError::CsvError(
+                        "Geometry column name cannot be empty".to_string(),
+                    ));
+                }
+                let idx = headers.iter().position(|h| h == col).ok_or_else(|| {
+                    N3gbError::CsvError(format!("Geometry column '{}' not found", col))
+                })?;
+                (SourceIndices::Geometry(idx), HashSet::from([idx]))
+            }
+            CoordinateSource::GeometryColumns(cols) => {
+                if cols.is_empty() {
+                    return Err(N3gbError::CsvError(
+                        "At least one geometry column is required".to_string(),
+                    ));
+                }
+                let mut indices = Vec::with_capacity(cols.len());
+                for col in cols {
+                    if col.is_empty() {
+                        return Err(N3gbError::CsvError(
+                            "Geometry column name cannot be empty".to_string(),
+                        ));
+                    }
+                    indices.push(headers.iter().position(|h| h == col).ok_or_else(|| {
+                        N3gbError::CsvError(format!("Geometry column '{}' not found", col))
+                    })?);
+                }
+                let exclude = indices.iter().copied().collect();
+                (SourceIndices::GeometryColumns(indices), exclude)
+            }
+            CoordinateSource::CoordinateColumns { x_column, y_column } => {
+                if x_column.is_empty() {
+                    return Err(N3gbError::CsvError(
+                        "X column name cannot be empty".to_string(),
+                    ));
+                }
+                if y_column.is_empty() {
+                    return Err(N3gbError::CsvError(
+                        "Y column name cannot be empty".to_string(),
+                    ));
+                }
+                let x_idx = headers.iter().position(|h| h == x_column).ok_or_else(|| {
+                    N3gbError::CsvError(format!("X column '{}' not found", x_column))
+                })?;
+                let y_idx = headers.iter().position(|h| h == y_column).ok_or_else(|| {
+                    N3gbError::CsvError(format!("Y column '{}' not found", y_column))
+                })?;
+                (
+                    SourceIndices::Coordinates { x_idx, y_idx },
+                    HashSet::from([x_idx, y_idx]),
+                )
+            }
+        };
+
+    if config.keep_source_columns {
+        exclude.clear();
+    }
+
+    for col_name in &config.exclude_columns {
+        if let Some(idx) = headers.iter().position(|h| h == col_name) {
+            exclude.insert(idx);
+        }
+    }
+
+    Ok((source_indices, exclude))
+}
+
+/// Read the next CSV record and convert it into hex cells, honouring `skip_errors`.
+///
+/// # Arguments
+/// * `result` - The record result yielded by the CSV reader.
+/// * `source_indices` - Resolved column indices identifying the geometry or X/Y columns.
+/// * `config` - Conversion configuration (zoom level, CRS, error policy).
+///
+/// # Returns
+/// The record and the hex cells it covers, or `None` if the record was skipped.
+///
+/// # Errors
+/// When `skip_errors` is unset, returns the same errors as reading the record or
+/// [`read_cells_from_record`].
+fn next_record_cells(
+    result: Result<csv::StringRecord, csv::Error>,
+    source_indices: &SourceIndices,
+    config: &CsvHexConfig,
+) -> Result<Option<(csv::StringRecord, Vec<HexCell>)>, N3gbError> {
+    let record = match result {
+        Ok(record) => record,
+        Err(_) if config.skip_errors => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    match read_cells_from_record(&record, source_indices, config) {
+        Ok(cells) => Ok(Some((record, cells))),
+        Err(N3gbError::CsvError(_) | N3gbError::GeometryParseError(_)) if config.skip_errors => {
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Count the input rows falling in each hex cell.
+///
+/// # Arguments
+/// * `reader` - The CSV reader positioned after the header row.
+/// * `source_indices` - Resolved column indices identifying the geometry or X/Y columns.
+/// * `config` - Conversion configuration controlling zoom, CRS, and error policy.
+///
+/// # Returns
+/// `(cell_id, count)` pairs sorted by descending count.
+///
+/// # Errors
+/// Returns the same errors as [`next_record_cells`].
+fn count_cells(
+    mut reader: CsvReader,
+    source_indices: &SourceIndices,
+    config: &CsvHexConfig,
+) -> Result<Vec<(String, usize)>, N3gbError> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for result in reader.records() {
+        let Some((_, cells)) = next_record_cells(result, source_indices, config)? else {
+            continue;
+        };
+
+        for cell in cells {
+            *counts.entry(cell.id).or_insert(0) += 1;
+        }
+    }
+
+    let mut sorted: Vec<_> = counts.into_iter().collect();
+    sorted.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+    Ok(sorted)
+}
+
 /// Aggregate records into one output row per hex cell with a count of input rows.
 ///
 /// # Arguments
@@ -256,31 +712,19 @@ fn read_cells_from_record(
 /// cannot be parsed; [`N3gbError::InvalidZoomLevel`] if the configured zoom level is
 /// invalid; and [`N3gbError::IoError`] if the output file cannot be created.
 fn csv_to_hex_density(
-    mut reader: csv::Reader<File>,
+    reader: CsvReader,
     source_indices: SourceIndices,
     output_path: impl AsRef<Path>,
     config: &CsvHexConfig,
 ) -> Result<(), N3gbError> {
-    let mut counts: HashMap<String, usize> = HashMap::new();
-
-    for result in reader.records() {
-        let record = result?;
-        let cells = read_cells_from_record(&record, &source_indices, config)?;
-
-        for cell in cells {
-            *counts.entry(cell.id).or_insert(0) += 1;
-        }
-    }
-
-    let mut sorted: Vec<_> = counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    let sorted = count_cells(reader, &source_indices, config)?;
 
     let out_file = File::create(output_path)?;
     let mut writer = csv::Writer::from_writer(out_file);
 
-    let mut header_row: Vec<&str> = vec!["hex_id", "count"];
+    let mut header_row: Vec<&str> = vec![&config.id_column_name, "count"];
     if config.include_hex_geometry.is_some() {
-        header_row.push("hex_geometry");
+        header_row.push(&config.geometry_column_name);
     }
     writer.write_record(&header_row)?;
 
@@ -289,12 +733,11 @@ fn csv_to_hex_density(
 
         if let Some(format) = config.include_hex_geometry {
             let cell = HexCell::from_hex_id(hex_id)?;
-            let polygon = cell.to_polygon();
-            let geom_str = match format {
-                GeometryFormat::Wkt => polygon_to_wkt(&polygon),
-                GeometryFormat::GeoJson => polygon_to_geojson(&polygon),
-            };
-            row.push(geom_str);
+            row.push(hex_geometry_string(
+                &[cell],
+                format,
+                config.geometry_precision,
+            ));
         }
 
         writer.write_record(&row)?;
@@ -305,33 +748,51 @@ fn csv_to_hex_density(
     Ok(())
 }
 
-/// Render a polygon as a Well-Known Text (WKT) string.
+/// Render the hexagons of one or more cells in the given format.
 ///
 /// # Arguments
-/// * `polygon` - The polygon to serialize.
+/// * `cells` - The cells to render; at least one.
+/// * `format` - The output format.
+/// * `precision` - Decimal places to round coordinates to, or `None` for full precision.
 ///
 /// # Returns
-/// The WKT representation of the polygon.
-fn polygon_to_wkt(polygon: &geo_types::Polygon<f64>) -> String {
+/// A `POLYGON` for a single cell in [`GeometryFormat::Wkt`] or
+/// [`GeometryFormat::GeoJson`], and a `MULTIPOLYGON` otherwise.
+fn hex_geometry_string(cells: &[HexCell], format: GeometryFormat, precision: Option<u8>) -> String {
+    use geo::MapCoords;
     use wkt::ToWkt;
-    polygon.wkt_string()
-}
 
-/// Render a polygon as a GeoJSON geometry string.
-///
-/// # Arguments
-/// * `polygon` - The polygon to serialize.
-///
-/// # Returns
-/// The GeoJSON representation of the polygon.
-fn polygon_to_geojson(polygon: &geo_types::Polygon<f64>) -> String {
-    let geom = geojson::Geometry::from(polygon);
-    geom.to_string()
+    let hexagon = |cell: &HexCell| match precision {
+        Some(decimals) => {
+            let factor = 10f64.powi(decimals as i32);
+            cell.to_polygon().map_coords(|c| Coord {
+                x: (c.x * factor).round() / factor,
+                y: (c.y * factor).round() / factor,
+            })
+        }
+        None => cell.to_polygon(),
+    };
+
+    match (format, cells) {
+        (GeometryFormat::Wkt, [cell]) => hexagon(cell).wkt_string(),
+        (GeometryFormat::GeoJson, [cell]) => geojson::Geometry::from(&hexagon(cell)).to_string(),
+        _ => {
+            let multipolygon: MultiPolygon<f64> = cells.iter().map(hexagon).collect();
+            match format {
+                GeometryFormat::Wkt | GeometryFormat::WktMultiPolygon => multipolygon.wkt_string(),
+                GeometryFormat::GeoJson | GeometryFormat::GeoJsonMultiPolygon => {
+                    geojson::Geometry::from(&multipolygon).to_string()
+                }
+            }
+        }
+    }
 }
 
 /// Converts a CSV file with geometry or coordinate columns to a CSV file with hex IDs.
 ///
-/// Streams output to minimize memory usage for large files.
+/// Streams output to minimize memory usage for large files. A UTF-8 byte order
+/// mark at the start of the file is ignored, so it does not stop the first
+/// column name from matching.
 ///
 /// # Example with geometry column (WKT or GeoJSON)
 ///
@@ -377,57 +838,11 @@ pub fn csv_to_hex_csv(
     output_path: impl AsRef<Path>,
     config: &CsvHexConfig,
 ) -> Result<(), N3gbError> {
-    let file = File::open(csv_path)?;
-    let mut reader = csv::Reader::from_reader(file);
+    let mut reader = open_csv_reader(csv_path, config)?;
 
     let headers = reader.headers()?.clone();
 
-    // Determine which columns to exclude based on source type
-    // Best practice is to always exclude ANY geometry column
-    let (source_indices, mut exclude_indices) =
-        match &config.source {
-            CoordinateSource::GeometryColumn(col) => {
-                if col.is_empty() {
-                    return Err(N3gbError::CsvError(
-                        "Geometry column name cannot be empty".to_string(),
-                    ));
-                }
-                let idx = headers.iter().position(|h| h == col).ok_or_else(|| {
-                    N3gbError::CsvError(format!("Geometry column '{}' not found", col))
-                })?;
-                let mut exclude = HashSet::new();
-                exclude.insert(idx);
-                (SourceIndices::Geometry(idx), exclude)
-            }
-            CoordinateSource::CoordinateColumns { x_column, y_column } => {
-                if x_column.is_empty() {
-                    return Err(N3gbError::CsvError(
-                        "X column name cannot be empty".to_string(),
-                    ));
-                }
-                if y_column.is_empty() {
-                    return Err(N3gbError::CsvError(
-                        "Y column name cannot be empty".to_string(),
-                    ));
-                }
-                let x_idx = headers.iter().position(|h| h == x_column).ok_or_else(|| {
-                    N3gbError::CsvError(format!("X column '{}' not found", x_column))
-                })?;
-                let y_idx = headers.iter().position(|h| h == y_column).ok_or_else(|| {
-                    N3gbError::CsvError(format!("Y column '{}' not found", y_column))
-                })?;
-                let mut exclude = HashSet::new();
-                exclude.insert(x_idx);
-                exclude.insert(y_idx);
-                (SourceIndices::Coordinates { x_idx, y_idx }, exclude)
-            }
-        };
-
-    for col_name in &config.exclude_columns {
-        if let Some(idx) = headers.iter().position(|h| h == col_name) {
-            exclude_indices.insert(idx);
-        }
-    }
+    let (source_indices, exclude_indices) = resolve_source_indices(&headers, config)?;
 
     if config.hex_density {
         return csv_to_hex_density(reader, source_indices, output_path, config);
@@ -436,9 +851,9 @@ pub fn csv_to_hex_csv(
     let out_file = File::create(output_path)?;
     let mut writer = csv::Writer::from_writer(out_file);
 
-    let mut header_row: Vec<&str> = vec!["hex_id"];
+    let mut header_row: Vec<&str> = vec![&config.id_column_name];
     if config.include_hex_geometry.is_some() {
-        header_row.push("hex_geometry");
+        header_row.push(&config.geometry_column_name);
     }
     for (i, h) in headers.iter().enumerate() {
         if !exclude_indices.contains(&i) {
@@ -447,26 +862,39 @@ pub fn csv_to_hex_csv(
     }
     writer.write_record(&header_row)?;
 
+    let mut emitted: HashSet<String> = HashSet::new();
+
     for result in reader.records() {
-        let record = result?;
+        let Some((record, cells)) = next_record_cells(result, &source_indices, config)? else {
+            continue;
+        };
 
-        let cells = read_cells_from_record(&record, &source_indices, config)?;
+        let cells: Vec<HexCell> = cells
+            .into_iter()
+            .filter(|cell| !config.dedup || emitted.insert(cell.id.clone()))
+            .collect();
 
-        for cell in cells {
-            let mut row: Vec<String> = vec![cell.id.clone()];
+        let groups: Vec<&[HexCell]> = match config.include_hex_geometry {
+            Some(format) if format.is_multi() && !cells.is_empty() => vec![&cells[..]],
+            Some(format) if format.is_multi() => Vec::new(),
+            _ => cells.chunks(1).collect(),
+        };
+
+        for group in groups {
+            let ids: Vec<&str> = group.iter().map(|cell| cell.id.as_str()).collect();
+            let mut row: Vec<String> = vec![ids.join(";")];
 
             if let Some(format) = config.include_hex_geometry {
-                let polygon = cell.to_polygon();
-                let geom_str = match format {
-                    GeometryFormat::Wkt => polygon_to_wkt(&polygon),
-                    GeometryFormat::GeoJson => polygon_to_geojson(&polygon),
-                };
-                row.push(geom_str);
+                row.push(hex_geometry_string(
+                    group,
+                    format,
+                    config.geometry_precision,
+                ));
             }
 
-            for (i, field) in record.iter().enumerate() {
+            for i in 0..headers.len() {
                 if !exclude_indices.contains(&i) {
-                    row.push(field.to_string());
+                    row.push(record.get(i).unwrap_or_default().to_string());
                 }
             }
             writer.write_record(&row)?;
@@ -478,9 +906,296 @@ pub fn csv_to_hex_csv(
     Ok(())
 }
 
+/// Adds a hex geometry column to a CSV file by decoding a column of hex IDs.
+///
+/// The reverse of [`csv_to_hex_csv`]: each ID is decoded with
+/// [`HexCell::from_hex_id`] and its hexagon, in BNG coordinates, is written to a
+/// new `hex_geometry` column appended after the existing columns. Streams output
+/// to minimize memory usage for large files.
+///
+/// # Example
+///
+/// ```no_run
+/// use n3gb_rs::{csv_expand_hex, GeometryFormat};
+///
+/// csv_expand_hex("hex_ids.csv", "hex_polygons.csv", "hex_id", GeometryFormat::Wkt).unwrap();
+/// ```
+///
+/// # Arguments
+/// * `csv_path` - Path of the input CSV file to read.
+/// * `output_path` - Path of the CSV file to write the expanded rows to.
+/// * `id_column` - Name of the CSV column holding the hex IDs.
+/// * `format` - Format of the appended geometry column.
+///
+/// # Returns
+/// `()` on success, after the output CSV has been written and flushed.
+///
+/// # Errors
+/// Returns [`N3gbError::CsvError`] if the input cannot be read, `id_column` is not found,
+/// or a record cannot be read or written; any error from [`decode_hex_identifier`] if an
+/// ID is invalid; and [`N3gbError::IoError`] if the input file cannot be opened or the
+/// output file cannot be created.
+///
+/// [`decode_hex_identifier`]: crate::decode_hex_identifier
+pub fn csv_expand_hex(
+    csv_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+    id_column: &str,
+    format: GeometryFormat,
+) -> Result<(), N3gbError> {
+    let file = File::open(csv_path)?;
+    let mut reader = csv::Reader::from_reader(file);
+
+    let headers = reader.headers()?.clone();
+    let id_idx = headers
+        .iter()
+        .position(|h| h == id_column)
+        .ok_or_else(|| N3gbError::CsvError(format!("ID column '{}' not found", id_column)))?;
+
+    let out_file = File::create(output_path)?;
+    let mut writer = csv::Writer::from_writer(out_file);
+
+    let mut header_row: Vec<&str> = headers.iter().collect();
+    header_row.push("hex_geometry");
+    writer.write_record(&header_row)?;
+
+    for result in reader.records() {
+        let record = result?;
+        let cell = HexCell::from_hex_id(record.get(id_idx).unwrap_or_default())?;
+        let geom_str = hex_geometry_string(&[cell], format, None);
+
+        let mut row: Vec<&str> = record.iter().collect();
+        row.push(&geom_str);
+        writer.write_record(&row)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Number of output rows buffered before each batch is written by [`csv_to_geoparquet`].
+const GEOPARQUET_BATCH_ROWS: usize = 8192;
+
+/// Converts a CSV file with geometry or coordinate columns directly to a GeoParquet file.
+///
+/// Produces the same rows as [`csv_to_hex_csv`], but written as GeoParquet with the
+/// standard cell columns from [`HexCellsToArrow::to_record_batch`] followed by the
+/// kept CSV columns as strings. Rows are streamed to the file in batches to bound
+/// memory use. With [`CsvHexConfig::hex_density`] the output has one row per cell
+/// with a `count` column instead. [`CsvHexConfig::with_hex_geometry`] and the
+/// output column names are ignored, since the hexagon is always written as the
+/// `geometry` column. A CSV column whose name is already taken, by a cell column
+/// such as `id` or by an earlier CSV column, is written with a `csv_` prefix.
+///
+/// # Example
+///
+/// ```no_run
+/// use n3gb_rs::{csv_to_geoparquet, CsvHexConfig, Crs};
+///
+/// let config = CsvHexConfig::from_coords("Easting", "Northing", 12).crs(Crs::Bng);
+/// csv_to_geoparquet("bus_stops.csv", "bus_stops.parquet", &config).unwrap();
+/// ```
+///
+/// # Arguments
+/// * `csv_path` - Path of the input CSV file to read.
+/// * `parquet_path` - Path of the GeoParquet file to write.
+/// * `config` - Conversion configuration describing the source columns, zoom, and CRS.
+///
+/// # Returns
+/// `()` on success, after the GeoParquet file has been written and finalized.
+///
+/// # Errors
+/// Returns [`N3gbError::CsvError`] if the input cannot be read, a configured column name
+/// is empty or not found, or a record cannot be read; [`N3gbError::GeometryParseError`]
+/// if a geometry value cannot be parsed; [`N3gbError::InvalidZoomLevel`] if the
+/// configured zoom level is invalid; and [`N3gbError::IoError`] if the input file cannot
+/// be opened or the GeoParquet file cannot be encoded or written.
+pub fn csv_to_geoparquet(
+    csv_path: impl AsRef<Path>,
+    parquet_path: impl AsRef<Path>,
+    config: &CsvHexConfig,
+) -> Result<(), N3gbError> {
+    let mut reader = open_csv_reader(csv_path, config)?;
+
+    let headers = reader.headers()?.clone();
+    let (source_indices, exclude_indices) = resolve_source_indices(&headers, config)?;
+    let mut writer = GeoParquetBatchWriter::new(parquet_path);
+
+    if config.hex_density {
+        let sorted = count_cells(reader, &source_indices, config)?;
+        for chunk in sorted.chunks(GEOPARQUET_BATCH_ROWS) {
+            let cells = chunk
+                .iter()
+                .map(|(id, _)| HexCell::from_hex_id(id))
+                .collect::<Result<Vec<_>, _>>()?;
+            let counts: ArrayRef = Arc::new(UInt64Array::from_iter_values(
+                chunk.iter().map(|(_, count)| *count as u64),
+            ));
+            writer.write(&cells.to_record_batch_with(&[("count", counts)])?)?;
+        }
+        if !writer.has_written() {
+            writer.write(&Vec::<HexCell>::new().to_record_batch_with(&[(
+                "count",
+                Arc::new(UInt64Array::from(Vec::<u64>::new())) as ArrayRef,
+            )])?)?;
+        }
+        return writer.finish();
+    }
+
+    let kept: Vec<usize> = (0..headers.len())
+        .filter(|i| !exclude_indices.contains(i))
+        .collect();
+    let names = geoparquet_column_names(&headers, &kept)?;
+    let mut cells: Vec<HexCell> = Vec::new();
+    let mut values: Vec<Vec<String>> = vec![Vec::new(); kept.len()];
+    let mut emitted: HashSet<String> = HashSet::new();
+
+    for result in reader.records() {
+        let Some((record, record_cells)) = next_record_cells(result, &source_indices, config)?
+        else {
+            continue;
+        };
+
+        for cell in record_cells {
+            if config.dedup && !emitted.insert(cell.id.clone()) {
+                continue;
+            }
+            for (column, &i) in values.iter_mut().zip(&kept) {
+                column.push(record.get(i).unwrap_or_default().to_string());
+            }
+            cells.push(cell);
+        }
+
+        if cells.len() >= GEOPARQUET_BATCH_ROWS {
+            writer.write(&csv_rows_to_batch(&cells, &names, &values)?)?;
+            cells.clear();
+            values.iter_mut().for_each(Vec::clear);
+        }
+    }
+
+    if !cells.is_empty() || !writer.has_written() {
+        writer.write(&csv_rows_to_batch(&cells, &names, &values)?)?;
+    }
+    writer.finish()
+}
+
+/// Chooses unique GeoParquet column names for the kept CSV columns.
+///
+/// # Arguments
+/// * `headers` - The header record of the input CSV.
+/// * `kept` - Indices of the CSV columns to append, in output order.
+///
+/// # Returns
+/// One name per kept column. A header already taken by a standard cell column or an
+/// earlier kept column is prefixed with `csv_` until it is unique.
+///
+/// # Errors
+/// Returns [`N3gbError::IoError`] if the standard cell columns cannot be built.
+fn geoparquet_column_names(
+    headers: &csv::StringRecord,
+    kept: &[usize],
+) -> Result<Vec<String>, N3gbError> {
+    let mut taken: HashSet<String> = Vec::<HexCell>::new()
+        .to_record_batch()?
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect();
+
+    Ok(kept
+        .iter()
+        .map(|&i| {
+            let mut name = headers[i].to_string();
+            while taken.contains(&name) {
+                name = format!("csv_{}", name);
+            }
+            taken.insert(name.clone());
+            name
+        })
+        .collect())
+}
+
+/// Build a record batch of cells with their source CSV columns appended as strings.
+///
+/// # Arguments
+/// * `cells` - The cells, one per output row.
+/// * `names` - The output name of each kept CSV column, in output order.
+/// * `values` - One vector of values per kept column, each with one value per cell.
+///
+/// # Returns
+/// A [`RecordBatch`] with the standard cell columns followed by the kept CSV columns.
+///
+/// # Errors
+/// Returns [`N3gbError::IoError`] if the columns cannot be assembled into a batch.
+fn csv_rows_to_batch(
+    cells: &[HexCell],
+    names: &[String],
+    values: &[Vec<String>],
+) -> Result<RecordBatch, N3gbError> {
+    let extra: Vec<(&str, ArrayRef)> = names
+        .iter()
+        .zip(values)
+        .map(|(name, column)| {
+            let array: ArrayRef = Arc::new(StringArray::from_iter_values(column));
+            (name.as_str(), array)
+        })
+        .collect();
+    cells.to_record_batch_with(&extra)
+}
+
+/// Checks that a CSV file can be converted with the given config, without writing output.
+///
+/// Opens the file, checks that the configured geometry or coordinate columns exist,
+/// and parses the first `sample_rows` records into hex cells. Use this as a dry run
+/// before a long [`csv_to_hex_csv`] conversion. Sampled rows are always checked
+/// strictly, even if [`CsvHexConfig::skip_errors`] is set.
+///
+/// # Example
+///
+/// ```no_run
+/// use n3gb_rs::{csv_validate, CsvHexConfig, Crs};
+///
+/// let config = CsvHexConfig::from_coords("Easting", "Northing", 12).crs(Crs::Bng);
+/// csv_validate("bus_stops.csv", &config, 100).unwrap();
+/// ```
+///
+/// # Arguments
+/// * `csv_path` - Path of the input CSV file to check.
+/// * `config` - Conversion configuration describing the source columns, zoom, and CRS.
+/// * `sample_rows` - Number of leading records to parse.
+///
+/// # Returns
+/// `()` if the header and all sampled records are valid.
+///
+/// # Errors
+/// Returns [`N3gbError::CsvError`] if the input cannot be read, a configured column is
+/// empty or not found, or a sampled record is malformed or has an invalid coordinate;
+/// [`N3gbError::GeometryParseError`] if a sampled geometry cannot be parsed;
+/// [`N3gbError::InvalidZoomLevel`] if the configured zoom level is invalid; and
+/// [`N3gbError::IoError`] if the input file cannot be opened.
+pub fn csv_validate(
+    csv_path: impl AsRef<Path>,
+    config: &CsvHexConfig,
+    sample_rows: usize,
+) -> Result<(), N3gbError> {
+    let mut reader = open_csv_reader(csv_path, config)?;
+
+    let headers = reader.headers()?.clone();
+    let (source_indices, _) = resolve_source_indices(&headers, config)?;
+
+    for result in reader.records().take(sample_rows) {
+        read_cells_from_record(&result?, &source_indices, config)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use geo_types::LineString;
     use std::io::Write;
     use tempfile::tempdir;
 
@@ -619,6 +1334,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_geometry_precision() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("test.csv");
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "ID,Easting,Northing")?;
+        writeln!(file, "1,359581,172304")?;
+        writeln!(file, "2,383640,398260")?;
+        drop(file);
+
+        let geometries = |config: CsvHexConfig| -> Result<Vec<String>, N3gbError> {
+            let output_path = dir.path().join("output.csv");
+            csv_to_hex_csv(&csv_path, &output_path, &config)?;
+            let mut reader = csv::Reader::from_path(&output_path)?;
+            let column = reader
+                .headers()?
+                .iter()
+                .position(|h| h == "hex_geometry")
+                .unwrap();
+            let mut out = Vec::new();
+            for record in reader.records() {
+                out.push(record?[column].to_string());
+            }
+            Ok(out)
+        };
+        let base = CsvHexConfig::from_coords("Easting", "Northing", 12).crs(Crs::Bng);
+
+        for format in [GeometryFormat::Wkt, GeometryFormat::GeoJson] {
+            let full = geometries(base.clone().with_hex_geometry(format))?;
+            let rounded = geometries(base.clone().with_hex_geometry(format).geometry_precision(1))?;
+
+            assert_eq!(full.len(), rounded.len());
+            for (full, rounded) in full.iter().zip(&rounded) {
+                assert!(rounded.len() < full.len());
+                let polygon = parse_geometry(rounded)?;
+                let geo_types::Geometry::Polygon(polygon) = polygon else {
+                    panic!("expected a polygon");
+                };
+                assert!(
+                    polygon
+                        .exterior()
+                        .coords()
+                        .all(|c| ((c.x * 10.0).round() - c.x * 10.0).abs() < 1e-6)
+                );
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_csv_hex_density_wgs84() -> Result<(), N3gbError> {
         let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
@@ -679,6 +1443,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_custom_column_names() -> Result<(), N3gbError> {
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let csv_path = dir.path().join("test.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&csv_path).map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "hex_id,Easting,Northing").map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "legacy,359581,172304").map_err(|e| N3gbError::IoError(e.to_string()))?;
+
+        let config = CsvHexConfig::from_coords("Easting", "Northing", 12)
+            .crs(Crs::Bng)
+            .with_hex_geometry(GeometryFormat::Wkt)
+            .id_column_name("n3gb_id")
+            .geometry_column_name("n3gb_geometry");
+        csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+        let output =
+            std::fs::read_to_string(&output_path).map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "n3gb_id,n3gb_geometry,hex_id");
+        assert!(lines[1].ends_with(",legacy"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_dedup_overlapping_lines() -> Result<(), N3gbError> {
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let csv_path = dir.path().join("test.csv");
+
+        let mut file = File::create(&csv_path).map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "ID,geometry").map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "1,\"LINESTRING(530000 180000, 530500 180000)\"")
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "2,\"LINESTRING(530250 180000, 530750 180000)\"")
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+
+        let read_ids = |dedup: bool| -> Result<Vec<String>, N3gbError> {
+            let output_path = dir.path().join(format!("output_{}.csv", dedup));
+            let config = CsvHexConfig::new("geometry", 10).crs(Crs::Bng).dedup(dedup);
+            csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+            let mut reader = csv::Reader::from_path(&output_path)?;
+            reader.records().map(|r| Ok(r?[0].to_string())).collect()
+        };
+
+        let all_ids = read_ids(false)?;
+        let unique: HashSet<&String> = all_ids.iter().collect();
+        assert!(unique.len() < all_ids.len());
+
+        let deduped_ids = read_ids(true)?;
+        let deduped_unique: HashSet<&String> = deduped_ids.iter().collect();
+        assert_eq!(deduped_unique.len(), deduped_ids.len());
+        assert_eq!(deduped_unique, unique);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_flexible_short_rows() -> Result<(), N3gbError> {
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let csv_path = dir.path().join("test.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&csv_path).map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "Easting,Northing,Name").map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "530000,180000,A").map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "531000,181000").map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writeln!(file, "532000").map_err(|e| N3gbError::IoError(e.to_string()))?;
+
+        let config = CsvHexConfig::from_coords("Easting", "Northing", 10).crs(Crs::Bng);
+        assert!(matches!(
+            csv_to_hex_csv(&csv_path, &output_path, &config),
+            Err(N3gbError::CsvError(_))
+        ));
+
+        let config = config.flexible(true);
+        assert!(matches!(
+            csv_to_hex_csv(&csv_path, &output_path, &config),
+            Err(N3gbError::CsvError(_))
+        ));
+
+        let config = config.skip_errors(true);
+        csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+        let mut reader = csv::Reader::from_path(&output_path)?;
+        let rows: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>()?;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(&rows[0][1], "A");
+        assert_eq!(&rows[1][1], "");
+        Ok(())
+    }
+
     #[test]
     fn test_csv_from_coords_wgs84() -> Result<(), N3gbError> {
         let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
@@ -697,4 +1554,384 @@ mod tests {
         assert!(output_path.exists());
         Ok(())
     }
+
+    #[test]
+    fn test_csv_validate() -> Result<(), N3gbError> {
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let input_path = dir.path().join("input.csv");
+
+        let mut file = File::create(&input_path)?;
+        writeln!(file, "name,geometry")?;
+        writeln!(file, "a,\"POINT(383640 398260)\"")?;
+        writeln!(file, "b,\"POINT(383700 398300)\"")?;
+        writeln!(file, "c,not a geometry")?;
+
+        let config = CsvHexConfig::new("geometry", 12).crs(Crs::Bng);
+        csv_validate(&input_path, &config, 2)?;
+        assert!(matches!(
+            csv_validate(&input_path, &config, 3),
+            Err(N3gbError::GeometryParseError(_))
+        ));
+
+        let missing = CsvHexConfig::new("wkt", 12).crs(Crs::Bng);
+        assert!(matches!(
+            csv_validate(&input_path, &missing, 2),
+            Err(N3gbError::CsvError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_to_geoparquet() -> Result<(), N3gbError> {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let input_path = dir.path().join("input.csv");
+        let output_path = dir.path().join("output.parquet");
+
+        let mut file = File::create(&input_path)?;
+        writeln!(file, "name,easting,northing")?;
+        writeln!(file, "a,383640,398260")?;
+        writeln!(file, "b,383700,398300")?;
+        writeln!(file, "c,390000,400000")?;
+
+        let config = CsvHexConfig::from_coords("easting", "northing", 12).crs(Crs::Bng);
+        csv_to_geoparquet(&input_path, &output_path, &config)?;
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&output_path)?)?;
+        assert_eq!(builder.metadata().file_metadata().num_rows(), 3);
+        let schema = builder.schema().clone();
+        assert!(schema.column_with_name("geometry").is_some());
+        assert!(schema.column_with_name("name").is_some());
+        assert!(schema.column_with_name("easting").is_some());
+        assert!(schema.column_with_name("northing").is_some());
+        assert_eq!(schema.fields().len(), 8);
+
+        let density = config.hex_density();
+        csv_to_geoparquet(&input_path, &output_path, &density)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&output_path)?)?;
+        assert!(builder.schema().column_with_name("count").is_some());
+        assert!(builder.metadata().file_metadata().num_rows() >= 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_to_geoparquet_renames_colliding_columns() -> Result<(), N3gbError> {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let input_path = dir.path().join("input.csv");
+        let output_path = dir.path().join("output.parquet");
+
+        let mut file = File::create(&input_path)?;
+        writeln!(file, "id,x,y,geometry,csv_id")?;
+        writeln!(file, "A1,383640,398260,kept,other")?;
+
+        let config = CsvHexConfig::from_coords("x", "y", 12).crs(Crs::Bng);
+        csv_to_geoparquet(&input_path, &output_path, &config)?;
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&output_path)?)?;
+        let schema = reader.schema().clone();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "id",
+                "zoom_level",
+                "row",
+                "col",
+                "easting",
+                "northing",
+                "geometry",
+                "csv_id",
+                "csv_geometry",
+                "csv_csv_id",
+            ]
+        );
+
+        let batch = reader.build()?.next().expect("one batch")?;
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                .map(|c| c.value(0).to_string())
+        };
+        let expected = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        assert_eq!(column("id"), Some(expected.id));
+        assert_eq!(column("csv_id").as_deref(), Some("A1"));
+        assert_eq!(column("csv_geometry").as_deref(), Some("kept"));
+        assert_eq!(column("csv_csv_id").as_deref(), Some("other"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_bom_header() -> Result<(), N3gbError> {
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let input_path = dir.path().join("input.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&input_path)?;
+        file.write_all(b"\xEF\xBB\xBFEASTING,NORTHING,ASSET_ID\n")?;
+        writeln!(file, "383640,398260,A1")?;
+
+        let config = CsvHexConfig::from_coords("EASTING", "NORTHING", 12).crs(Crs::Bng);
+        csv_validate(&input_path, &config, 1)?;
+        csv_to_hex_csv(&input_path, &output_path, &config)?;
+
+        let output = std::fs::read_to_string(&output_path)?;
+        let expected = HexCell::from_bng(&(383640.0, 398260.0), 12)?;
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            vec!["hex_id,ASSET_ID".to_string(), format!("{},A1", expected.id)]
+        );
+
+        let mut file = File::create(&input_path)?;
+        file.write_all(b"\xEF\xBB\xBFASSET_ID,geometry\n")?;
+        writeln!(file, "A1,POINT(383640 398260)")?;
+
+        let config = CsvHexConfig::new("geometry", 12)
+            .crs(Crs::Bng)
+            .exclude(vec!["ASSET_ID".into()]);
+        csv_to_hex_csv(&input_path, &output_path, &config)?;
+        let output = std::fs::read_to_string(&output_path)?;
+        assert_eq!(output.lines().nth(1), Some(expected.id.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_expand_hex() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("ids.csv");
+        let output_path = dir.path().join("expanded.csv");
+
+        let cells = [
+            HexCell::from_bng(&(383640.0, 398260.0), 10)?,
+            HexCell::from_bng(&(457500.0, 340000.0), 12)?,
+        ];
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "name,hex_id")?;
+        writeln!(file, "a,{}", cells[0].id)?;
+        writeln!(file, "b,{}", cells[1].id)?;
+        drop(file);
+
+        csv_expand_hex(&csv_path, &output_path, "hex_id", GeometryFormat::Wkt)?;
+
+        let mut reader = csv::Reader::from_path(&output_path)?;
+        assert_eq!(
+            reader.headers()?.iter().collect::<Vec<_>>(),
+            vec!["name", "hex_id", "hex_geometry"]
+        );
+        let records: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>()?;
+        assert_eq!(records.len(), cells.len());
+
+        for (record, cell) in records.iter().zip(&cells) {
+            assert_eq!(&record[1], cell.id);
+            let geo_types::Geometry::Polygon(polygon) = parse_geometry(&record[2])? else {
+                panic!("expected a polygon");
+            };
+            assert_eq!(
+                polygon.exterior().0.len(),
+                cell.to_polygon().exterior().0.len()
+            );
+            let centroid = geo::Centroid::centroid(&polygon).unwrap();
+            assert!((centroid.x() - cell.easting()).abs() < 1e-3);
+            assert!((centroid.y() - cell.northing()).abs() < 1e-3);
+        }
+
+        csv_expand_hex(&csv_path, &output_path, "hex_id", GeometryFormat::GeoJson)?;
+        let mut reader = csv::Reader::from_path(&output_path)?;
+        let first = reader.records().next().unwrap()?;
+        assert!(first[2].contains("\"Polygon\""));
+
+        assert!(matches!(
+            csv_expand_hex(&csv_path, &output_path, "missing", GeometryFormat::Wkt),
+            Err(N3gbError::CsvError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_to_hex_csv_multipolygon_per_row() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("lines.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let line = "LINESTRING(457000 339500, 458000 339500)";
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "name,geometry")?;
+        writeln!(file, "road,\"{}\"", line)?;
+        writeln!(file, "stop,\"POINT(383640 398260)\"")?;
+        drop(file);
+
+        let expected = HexCell::from_line_string_bng(
+            &geo_types::LineString::from(vec![(457000.0, 339500.0), (458000.0, 339500.0)]),
+            10,
+        )?;
+        assert!(expected.len() > 1);
+
+        for (format, prefix) in [
+            (GeometryFormat::WktMultiPolygon, "MULTIPOLYGON"),
+            (GeometryFormat::GeoJsonMultiPolygon, "{"),
+        ] {
+            let config = CsvHexConfig::new("geometry", 10)
+                .crs(Crs::Bng)
+                .exclude(vec!["geometry".into()])
+                .with_hex_geometry(format);
+            csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+            let mut reader = csv::Reader::from_path(&output_path)?;
+            let records: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>()?;
+            assert_eq!(records.len(), 2);
+
+            let ids: Vec<&str> = records[0][0].split(';').collect();
+            assert_eq!(
+                ids,
+                expected.iter().map(|c| c.id.as_str()).collect::<Vec<_>>()
+            );
+            assert!(records[0][1].starts_with(prefix));
+            let geo_types::Geometry::MultiPolygon(mp) = parse_geometry(&records[0][1])? else {
+                panic!("expected a MultiPolygon");
+            };
+            assert_eq!(mp.0.len(), expected.len());
+            assert_eq!(&records[0][2], "road");
+
+            let geo_types::Geometry::MultiPolygon(single) = parse_geometry(&records[1][1])? else {
+                panic!("expected a MultiPolygon");
+            };
+            assert_eq!(single.0.len(), 1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keep_source_columns() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("stops.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "name,Easting,Northing")?;
+        writeln!(file, "stop,383640,398260")?;
+        drop(file);
+
+        let headers = |config: &CsvHexConfig| -> Result<Vec<String>, N3gbError> {
+            csv_to_hex_csv(&csv_path, &output_path, config)?;
+            let mut reader = csv::Reader::from_path(&output_path)?;
+            Ok(reader.headers()?.iter().map(String::from).collect())
+        };
+
+        let config = CsvHexConfig::from_coords("Easting", "Northing", 10).crs(Crs::Bng);
+        assert_eq!(headers(&config)?, vec!["hex_id", "name"]);
+
+        let config = config.keep_source_columns(true);
+        assert_eq!(
+            headers(&config)?,
+            vec!["hex_id", "name", "Easting", "Northing"]
+        );
+        let mut reader = csv::Reader::from_path(&output_path)?;
+        let record = reader.records().next().unwrap()?;
+        assert_eq!((&record[2], &record[3]), ("383640", "398260"));
+
+        let config = config.exclude(vec!["Northing".into()]);
+        assert_eq!(headers(&config)?, vec!["hex_id", "name", "Easting"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_geometry_columns_first_non_empty() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("assets.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "name,Geo Point,Geo Shape")?;
+        writeln!(file, "a,POINT(383640 398260),")?;
+        writeln!(file, "b,,\"LINESTRING(457000 340000, 457500 340000)\"")?;
+        writeln!(file, "c,POINT(457000 340000),POINT(383640 398260)")?;
+        writeln!(file, "d, ,POINT(390000 400000)")?;
+        drop(file);
+
+        let config =
+            CsvHexConfig::from_geometry_columns(["Geo Point", "Geo Shape"], 10).crs(Crs::Bng);
+        csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+        let mut reader = csv::Reader::from_path(&output_path)?;
+        assert_eq!(
+            reader.headers()?.iter().collect::<Vec<_>>(),
+            vec!["hex_id", "name"]
+        );
+        let rows: Vec<(String, String)> = reader
+            .records()
+            .map(|r| {
+                let r = r?;
+                Ok((r[0].to_string(), r[1].to_string()))
+            })
+            .collect::<Result<_, N3gbError>>()?;
+
+        let ids_for = |name: &str| -> Vec<&str> {
+            rows.iter()
+                .filter(|(_, n)| n == name)
+                .map(|(id, _)| id.as_str())
+                .collect()
+        };
+        let line = LineString::from(vec![(457000.0, 340000.0), (457500.0, 340000.0)]);
+        let line_ids: Vec<String> = HexCell::from_line_string_bng(&line, 10)?
+            .into_iter()
+            .map(|cell| cell.id)
+            .collect();
+
+        assert_eq!(
+            ids_for("a"),
+            vec![HexCell::from_bng(&(383640.0, 398260.0), 10)?.id]
+        );
+        assert_eq!(ids_for("b"), line_ids);
+        assert_eq!(
+            ids_for("c"),
+            vec![HexCell::from_bng(&(457000.0, 340000.0), 10)?.id]
+        );
+        assert_eq!(
+            ids_for("d"),
+            vec![HexCell::from_bng(&(390000.0, 400000.0), 10)?.id]
+        );
+
+        let mut file = File::create(&csv_path)?;
+        writeln!(file, "name,Geo Point,Geo Shape")?;
+        writeln!(file, "e,,")?;
+        drop(file);
+        assert!(matches!(
+            csv_to_hex_csv(&csv_path, &output_path, &config),
+            Err(N3gbError::CsvError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_windows_1252_encoding() -> Result<(), N3gbError> {
+        let dir = tempdir()?;
+        let csv_path = dir.path().join("latin1.csv");
+        let output_path = dir.path().join("output.csv");
+
+        let mut file = File::create(&csv_path)?;
+        file.write_all(b"name,Easting,Northing\n")?;
+        file.write_all(b"Caf\xE9 No\xEBl,383640,398260\n")?;
+        drop(file);
+
+        let config = CsvHexConfig::from_coords("Easting", "Northing", 10).crs(Crs::Bng);
+        assert!(matches!(
+            csv_to_hex_csv(&csv_path, &output_path, &config),
+            Err(N3gbError::CsvError(_))
+        ));
+
+        let config = config.encoding(CsvEncoding::Windows1252);
+        csv_validate(&csv_path, &config, 1)?;
+        csv_to_hex_csv(&csv_path, &output_path, &config)?;
+
+        let output = std::fs::read_to_string(&output_path)?;
+        let expected = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        assert_eq!(
+            output.lines().nth(1),
+            Some(format!("{},Café Noël", expected.id).as_str())
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,307 @@
+use crate::cell::HexCell;
+use crate::coord::{convert_polygon_to_wgs84, convert_to_wgs84, preferred_method};
+use crate::error::N3gbError;
+use crate::index::CELL_WIDTHS;
+use crate::io::arrow::RecordBatchFields;
+use crate::parallel::*;
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Trait for converting collections of [`HexCell`]s to GeoJSON.
+///
+/// Implemented for any type that dereferences to `[HexCell]` (e.g. `Vec<HexCell>`, `&[HexCell]`).
+/// Output is a `FeatureCollection` with one hexagon polygon per cell, reprojected to WGS84
+/// as required by RFC 7946, or newline-delimited GeoJSON with one feature per line.
+pub trait HexCellsToGeoJson {
+    /// Converts cells to a GeoJSON `FeatureCollection`.
+    ///
+    /// Each feature carries `id`, `zoom_level`, `row`, and `col` properties.
+    ///
+    /// # Returns
+    ///
+    /// A [`GeoJson::FeatureCollection`] with one WGS84 hexagon polygon per cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ProjectionError`] if a hexagon cannot be reprojected to WGS84.
+    fn to_geojson_value(&self) -> Result<GeoJson, N3gbError>;
+    /// Converts cells to a serialized GeoJSON `FeatureCollection` string.
+    ///
+    /// # Returns
+    ///
+    /// The `FeatureCollection` produced by [`HexCellsToGeoJson::to_geojson_value`] as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ProjectionError`] if a hexagon cannot be reprojected to WGS84.
+    fn to_geojson_string(&self) -> Result<String, N3gbError>;
+    /// Writes cells to a newline-delimited GeoJSON (GeoJSONL) file.
+    ///
+    /// Each line holds one WGS84 hexagon `Feature`, with the same properties as
+    /// [`HexCellsToGeoJson::to_geojson_value`], so the file can be streamed by
+    /// tools that read one feature at a time. An empty input writes an empty file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The output file path. An existing file is overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::ProjectionError`] if a hexagon cannot be reprojected to WGS84,
+    /// or [`N3gbError::IoError`] if the file cannot be written.
+    fn to_geojsonl_file(&self, path: impl AsRef<Path>) -> Result<(), N3gbError>;
+}
+
+impl<T: AsRef<[HexCell]>> HexCellsToGeoJson for T {
+    fn to_geojson_value(&self) -> Result<GeoJson, N3gbError> {
+        Ok(GeoJson::FeatureCollection(FeatureCollection {
+            bbox: None,
+            features: cells_to_features(self.as_ref(), &default_properties(), |_| false)?,
+            foreign_members: None,
+        }))
+    }
+
+    fn to_geojson_string(&self) -> Result<String, N3gbError> {
+        Ok(self.to_geojson_value()?.to_string())
+    }
+
+    fn to_geojsonl_file(&self, path: impl AsRef<Path>) -> Result<(), N3gbError> {
+        let features = cells_to_features(self.as_ref(), &default_properties(), |_| false)?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        for feature in &features {
+            writeln!(writer, "{}", feature)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Converts cells to a GeoJSON `FeatureCollection`, using points for cells too
+/// small to see at the given map scale.
+///
+/// A cell renders `width / map_scale` pixels wide; when that is below
+/// `min_cell_px` the cell is written as its WGS84 center point instead of a
+/// hexagon.
+///
+/// # Arguments
+///
+/// * `cells` - The cells to convert.
+/// * `min_cell_px` - The smallest on-screen width, in pixels, drawn as a hexagon.
+/// * `map_scale` - The ground distance covered by one pixel, in meters.
+///
+/// # Returns
+///
+/// A [`GeoJson::FeatureCollection`] with one feature per cell, in input order.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::ProjectionError`] if a cell cannot be reprojected to WGS84.
+pub(crate) fn cells_to_geojson_simplified(
+    cells: &[HexCell],
+    min_cell_px: f64,
+    map_scale: f64,
+) -> Result<GeoJson, N3gbError> {
+    let features = cells_to_features(cells, &default_properties(), |cell| {
+        CELL_WIDTHS[cell.zoom_level as usize] / map_scale < min_cell_px
+    })?;
+
+    Ok(GeoJson::FeatureCollection(FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }))
+}
+
+/// Converts cells to a GeoJSON `FeatureCollection` with only the named properties.
+///
+/// # Arguments
+///
+/// * `cells` - The cells to convert.
+/// * `props` - The properties to write, from `id`, `zoom_level`, `row`, `col`,
+///   `easting` and `northing`. An empty slice writes all of them.
+///
+/// # Returns
+///
+/// A [`GeoJson::FeatureCollection`] with one WGS84 hexagon polygon per cell.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::InvalidArgument`] if a property name is not recognised,
+/// or [`N3gbError::ProjectionError`] if a hexagon cannot be reprojected to WGS84.
+pub(crate) fn cells_to_geojson_with(
+    cells: &[HexCell],
+    props: &[&str],
+) -> Result<GeoJson, N3gbError> {
+    let mut fields = if props.is_empty() {
+        RecordBatchFields::all()
+    } else {
+        RecordBatchFields::none()
+    };
+    for &name in props {
+        fields = match name {
+            "id" => fields.id(true),
+            "zoom_level" => fields.zoom_level(true),
+            "row" => fields.row(true),
+            "col" => fields.col(true),
+            "easting" => fields.easting(true),
+            "northing" => fields.northing(true),
+            _ => {
+                return Err(N3gbError::InvalidArgument(format!(
+                    "Unknown GeoJSON property '{}'",
+                    name
+                )));
+            }
+        };
+    }
+
+    Ok(GeoJson::FeatureCollection(FeatureCollection {
+        bbox: None,
+        features: cells_to_features(cells, &fields, |_| false)?,
+        foreign_members: None,
+    }))
+}
+
+/// The properties written when none are chosen explicitly.
+fn default_properties() -> RecordBatchFields {
+    RecordBatchFields::none()
+        .id(true)
+        .zoom_level(true)
+        .row(true)
+        .col(true)
+}
+
+/// Converts cells to WGS84 features, in parallel.
+///
+/// # Arguments
+///
+/// * `cells` - The cells to convert.
+/// * `props` - The properties to set on each feature; `geometry` is ignored.
+/// * `as_point` - Returns `true` for cells to write as their center point
+///   rather than their hexagon.
+///
+/// # Returns
+///
+/// One `Feature` per cell, in input order, carrying the selected properties.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::ProjectionError`] if a cell cannot be reprojected to WGS84.
+fn cells_to_features<F>(
+    cells: &[HexCell],
+    props: &RecordBatchFields,
+    as_point: F,
+) -> Result<Vec<Feature>, N3gbError>
+where
+    F: Fn(&HexCell) -> bool + Sync,
+{
+    let method = preferred_method();
+    cells
+        .par_iter()
+        .map(|cell| {
+            let geometry = if as_point(cell) {
+                Geometry::from(&convert_to_wgs84(&cell.center, method)?)
+            } else {
+                Geometry::from(&convert_polygon_to_wgs84(&cell.to_polygon(), method)?)
+            };
+            let mut feature = Feature::from(geometry);
+            if props.id {
+                feature.set_property("id", cell.id.clone());
+            }
+            if props.zoom_level {
+                feature.set_property("zoom_level", cell.zoom_level);
+            }
+            if props.row {
+                feature.set_property("row", cell.row);
+            }
+            if props.col {
+                feature.set_property("col", cell.col);
+            }
+            if props.easting {
+                feature.set_property("easting", cell.easting());
+            }
+            if props.northing {
+                feature.set_property("northing", cell.northing());
+            }
+            Ok(feature)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_to_geojson() -> Result<(), N3gbError> {
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+
+        let GeoJson::FeatureCollection(fc) = cells.to_geojson_value()? else {
+            panic!("expected a FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 2);
+
+        let feature = &fc.features[0];
+        assert_eq!(
+            feature.property("id").and_then(|v| v.as_str()),
+            Some(cells[0].id.as_str())
+        );
+        let Some(geojson::Value::Polygon(rings)) = feature.geometry.as_ref().map(|g| &g.value)
+        else {
+            panic!("expected a Polygon geometry");
+        };
+        for position in &rings[0] {
+            assert!(position[0] > -3.0 && position[0] < -2.0);
+            assert!(position[1] > 53.0 && position[1] < 54.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_to_geojson_string() -> Result<(), N3gbError> {
+        let cells = [
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+
+        let slice: &[HexCell] = &cells[..1];
+        let json = slice.to_geojson_string()?;
+        assert!(json.contains("FeatureCollection"));
+        assert!(json.contains(&cells[0].id));
+        assert!(!json.contains(&cells[1].id));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_geojsonl_file() -> Result<(), N3gbError> {
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+            HexCell::from_bng(&(457500.0, 340000.0), 12)?,
+        ];
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cells.geojsonl");
+        cells.to_geojsonl_file(&path)?;
+
+        let contents = std::fs::read_to_string(&path)?;
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), cells.len());
+
+        for (line, cell) in lines.iter().zip(&cells) {
+            let GeoJson::Feature(feature) = line.parse::<GeoJson>().expect("valid GeoJSON") else {
+                panic!("expected a Feature");
+            };
+            assert_eq!(
+                feature.property("id").and_then(|v| v.as_str()),
+                Some(cell.id.as_str())
+            );
+        }
+        Ok(())
+    }
+}
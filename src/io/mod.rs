@@ -1,7 +1,15 @@
 pub mod arrow;
 pub mod csv;
+pub mod geojson;
 pub mod parquet;
 
-pub use arrow::HexCellsToArrow;
-pub use csv::{CoordinateSource, CsvHexConfig, GeometryFormat, csv_to_hex_csv};
-pub use parquet::{HexCellsToGeoParquet, write_geoparquet};
+pub use arrow::{HexCellsToArrow, RecordBatchFields};
+pub use csv::{
+    CoordinateSource, CsvEncoding, CsvHexConfig, GeometryFormat, csv_expand_hex, csv_to_geoparquet,
+    csv_to_hex_csv, csv_validate,
+};
+pub use geojson::HexCellsToGeoJson;
+pub use parquet::{
+    GeoParquetEncoding, GeoParquetOptions, HexCellsToGeoParquet, append_geoparquet,
+    lines_to_geoparquet, write_geoparquet, write_geoparquet_with_options,
+};
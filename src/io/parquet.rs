@@ -1,17 +1,68 @@
-use crate::cell::HexCell;
+use crate::cell::{HexCell, LineCellDeduper};
+use crate::coord::{Crs, convert_line_to_bng, preferred_method};
 use crate::error::N3gbError;
 use crate::io::arrow::HexCellsToArrow;
 use arrow_array::RecordBatch;
+use geo_types::LineString;
+use geoparquet::metadata::GeoParquetMetadata;
 use geoparquet::writer::{
     GeoParquetRecordBatchEncoder, GeoParquetWriterEncoding, GeoParquetWriterOptionsBuilder,
 };
 use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::format::KeyValue;
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Geometry encoding used for the geometry column of a GeoParquet file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoParquetEncoding {
+    /// Well-Known Binary, the only encoding in GeoParquet 1.0 and the most widely readable
+    #[default]
+    Wkb,
+    /// Native GeoArrow encoding (GeoParquet 1.1), faster to read for supporting readers
+    Native,
+}
+
+/// Options controlling how GeoParquet files are written.
+#[derive(Debug, Clone, Default)]
+pub struct GeoParquetOptions {
+    pub encoding: GeoParquetEncoding,
+}
+
+impl GeoParquetOptions {
+    /// Create options with the default settings (WKB encoding).
+    ///
+    /// # Returns
+    /// A new [`GeoParquetOptions`] with default settings.
+    ///
+    /// # Example
+    /// ```
+    /// use n3gb_rs::{GeoParquetEncoding, GeoParquetOptions};
+    ///
+    /// let options = GeoParquetOptions::new().encoding(GeoParquetEncoding::Native);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the geometry encoding.
+    ///
+    /// # Arguments
+    /// * `encoding` - The encoding to use for the geometry column.
+    ///
+    /// # Returns
+    /// The updated options for chaining.
+    pub fn encoding(mut self, encoding: GeoParquetEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+}
 
 /// Writes an Arrow RecordBatch to a GeoParquet file.
 ///
 /// The batch should contain a geometry column (normally from [`HexCellsToArrow::to_record_batch`]).
+/// Geometry is WKB-encoded; use [`write_geoparquet_with_options`] to choose another encoding.
 ///
 /// # Arguments
 ///
@@ -28,13 +79,42 @@ use std::path::Path;
 /// batch cannot be encoded, if the key-value metadata cannot be produced, or if the
 /// underlying file cannot be created or written (via `From<io::Error>` / `From<ParquetError>`).
 pub fn write_geoparquet(batch: &RecordBatch, path: impl AsRef<Path>) -> Result<(), N3gbError> {
+    write_geoparquet_with_options(batch, path, &GeoParquetOptions::default())
+}
+
+/// Writes an Arrow RecordBatch to a GeoParquet file using the given options.
+///
+/// # Arguments
+///
+/// * `batch` - The Arrow [`RecordBatch`] to encode, containing a geometry column.
+/// * `path` - Filesystem path where the GeoParquet file is written.
+/// * `options` - Writer options, such as the geometry encoding.
+///
+/// # Returns
+///
+/// `()` on success, after the GeoParquet file has been fully written and finalized.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::IoError`] if the GeoParquet encoder cannot be created, if the
+/// batch cannot be encoded, if the key-value metadata cannot be produced, or if the
+/// underlying file cannot be created or written (via `From<io::Error>` / `From<ParquetError>`).
+pub fn write_geoparquet_with_options(
+    batch: &RecordBatch,
+    path: impl AsRef<Path>,
+    options: &GeoParquetOptions,
+) -> Result<(), N3gbError> {
     let schema = batch.schema();
 
-    let options = GeoParquetWriterOptionsBuilder::default()
-        .set_encoding(GeoParquetWriterEncoding::WKB)
+    let encoding = match options.encoding {
+        GeoParquetEncoding::Wkb => GeoParquetWriterEncoding::WKB,
+        GeoParquetEncoding::Native => GeoParquetWriterEncoding::GeoArrow,
+    };
+    let writer_options = GeoParquetWriterOptionsBuilder::default()
+        .set_encoding(encoding)
         .build();
 
-    let mut encoder = GeoParquetRecordBatchEncoder::try_new(&schema, &options)
+    let mut encoder = GeoParquetRecordBatchEncoder::try_new(&schema, &writer_options)
         .map_err(|e| N3gbError::IoError(e.to_string()))?;
 
     let file = File::create(path)?;
@@ -56,6 +136,268 @@ pub fn write_geoparquet(batch: &RecordBatch, path: impl AsRef<Path>) -> Result<(
     Ok(())
 }
 
+/// Appends an Arrow RecordBatch to a GeoParquet file, creating the file if it does not exist.
+///
+/// Parquet files cannot be extended in place, so the existing file is streamed
+/// row group by row group into a temporary file, the new batch is added as a
+/// final row group, and the temporary file then replaces the original. Only one
+/// row group is held in memory at a time. The GeoParquet `geo` metadata is
+/// merged so the file bounding box covers both old and new rows.
+///
+/// The batch is WKB-encoded, matching [`write_geoparquet`]; appending to a file
+/// written with another encoding fails the schema check.
+///
+/// # Arguments
+///
+/// * `batch` - The Arrow [`RecordBatch`] to append, containing a geometry column.
+/// * `path` - Filesystem path of the GeoParquet file to append to.
+///
+/// # Returns
+///
+/// `()` on success, after the combined file has replaced the original.
+///
+/// # Errors
+///
+/// Returns [`N3gbError::IoError`] if the existing file cannot be read or has no
+/// GeoParquet metadata, if the batch's encoded columns (names and types) differ
+/// from the existing file's, or if the combined file cannot be encoded, written
+/// or moved into place.
+pub fn append_geoparquet(batch: &RecordBatch, path: impl AsRef<Path>) -> Result<(), N3gbError> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return write_geoparquet(batch, path);
+    }
+
+    let writer_options = GeoParquetWriterOptionsBuilder::default()
+        .set_encoding(GeoParquetWriterEncoding::WKB)
+        .build();
+    let mut encoder = GeoParquetRecordBatchEncoder::try_new(&batch.schema(), &writer_options)
+        .map_err(|e| N3gbError::IoError(e.to_string()))?;
+    let target_schema = encoder.target_schema();
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+    let mut geo_metadata =
+        GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata())
+            .ok_or_else(|| {
+                N3gbError::IoError(format!("'{}' has no GeoParquet metadata", path.display()))
+            })?
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+
+    let existing_fields = builder.schema().fields();
+    let compatible = existing_fields.len() == target_schema.fields().len()
+        && existing_fields
+            .iter()
+            .zip(target_schema.fields())
+            .all(|(a, b)| a.name() == b.name() && a.data_type() == b.data_type());
+    if !compatible {
+        return Err(N3gbError::IoError(format!(
+            "Schema of appended batch does not match '{}'",
+            path.display()
+        )));
+    }
+
+    let num_row_groups = builder.metadata().num_row_groups();
+    drop(builder);
+
+    let mut tmp_path = PathBuf::from(path).into_os_string();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let result = (|| {
+        let mut writer =
+            ArrowWriter::try_new(File::create(&tmp_path)?, target_schema.clone(), None)?;
+        // Copy one row group at a time, keeping the original row group boundaries
+        for row_group in 0..num_row_groups {
+            let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?
+                .with_row_groups(vec![row_group])
+                .build()?;
+            for existing in reader {
+                let existing =
+                    RecordBatch::try_new(target_schema.clone(), existing?.columns().to_vec())?;
+                writer.write(&existing)?;
+            }
+            writer.flush()?;
+        }
+
+        let encoded_batch = encoder
+            .encode_record_batch(batch)
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writer.write(&encoded_batch)?;
+
+        geo_metadata
+            .try_update(&encoder.into_geoparquet_metadata())
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let geo_json =
+            serde_json::to_string(&geo_metadata).map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writer.append_key_value_metadata(KeyValue::new("geo".to_string(), geo_json));
+        writer.finish()?;
+
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+/// Number of cells buffered before each batch is written by [`lines_to_geoparquet`].
+const LINE_BATCH_CELLS: usize = 8192;
+
+/// Converts a stream of lines to cells and writes the unique cells to GeoParquet.
+///
+/// Each line is converted as it is pulled from the iterator, and new cells are
+/// written in batches, so neither the lines nor the cells are all held in
+/// memory at once. Only the grid positions of cells already written are kept,
+/// in a [`LineCellDeduper`], to drop cells shared between lines. Cells are written in the order they are first
+/// reached. WGS84 lines are projected with the preferred conversion method.
+///
+/// # Example
+///
+/// ```no_run
+/// use n3gb_rs::{Crs, lines_to_geoparquet};
+/// use geo_types::line_string;
+///
+/// let lines = vec![
+///     line_string![(x: 383000.0, y: 398000.0), (x: 385000.0, y: 399000.0)],
+///     line_string![(x: 385000.0, y: 399000.0), (x: 386000.0, y: 401000.0)],
+/// ];
+/// let written = lines_to_geoparquet(lines, 12, Crs::Bng, "route.parquet").unwrap();
+/// println!("{} cells", written);
+/// ```
+///
+/// # Arguments
+/// * `lines` - The lines to convert, in the coordinate system given by `crs`.
+/// * `zoom_level` - The zoom level (0-15) at which to generate cells.
+/// * `crs` - The coordinate reference system of the lines.
+/// * `path` - Filesystem path where the GeoParquet file is written.
+///
+/// # Returns
+/// The number of unique cells written.
+///
+/// # Errors
+/// Returns [`N3gbError::InvalidZoomLevel`] if `zoom_level` is invalid,
+/// [`N3gbError::ProjectionError`] if a WGS84 line cannot be projected,
+/// [`N3gbError::OutOfBounds`] if a line leaves the grid, and
+/// [`N3gbError::IoError`] if the GeoParquet file cannot be encoded or written.
+pub fn lines_to_geoparquet(
+    lines: impl IntoIterator<Item = LineString<f64>>,
+    zoom_level: u8,
+    crs: Crs,
+    path: impl AsRef<Path>,
+) -> Result<usize, N3gbError> {
+    let method = preferred_method();
+    let mut writer = GeoParquetBatchWriter::new(path);
+    let mut deduper = LineCellDeduper::new(zoom_level);
+    let mut pending: Vec<HexCell> = Vec::with_capacity(LINE_BATCH_CELLS);
+
+    for line in lines {
+        let line = match crs {
+            Crs::Bng => line,
+            Crs::Wgs84 => convert_line_to_bng(&line, method)?,
+        };
+        pending.extend(HexCell::from_line_string_bng_deduped(&line, &mut deduper)?);
+        if pending.len() >= LINE_BATCH_CELLS {
+            writer.write(&pending.to_record_batch()?)?;
+            pending.clear();
+        }
+    }
+
+    if !pending.is_empty() || !writer.has_written() {
+        writer.write(&pending.to_record_batch()?)?;
+    }
+    writer.finish()?;
+    Ok(deduper.len())
+}
+
+/// Incrementally writes record batches to a WKB-encoded GeoParquet file.
+///
+/// The encoder and file are created lazily from the schema of the first batch,
+/// and every later batch must share that schema. Each batch is encoded and
+/// written as it arrives, so callers can stream large inputs.
+pub(crate) struct GeoParquetBatchWriter {
+    path: PathBuf,
+    inner: Option<(GeoParquetRecordBatchEncoder, ArrowWriter<File>)>,
+}
+
+impl GeoParquetBatchWriter {
+    /// Create a writer for the given output path. No file is created until the first write.
+    ///
+    /// # Arguments
+    /// * `path` - Filesystem path where the GeoParquet file is written.
+    ///
+    /// # Returns
+    /// A new [`GeoParquetBatchWriter`].
+    pub(crate) fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            inner: None,
+        }
+    }
+
+    /// Returns whether at least one batch has been written.
+    ///
+    /// # Returns
+    /// `true` once [`GeoParquetBatchWriter::write`] has succeeded at least once.
+    pub(crate) fn has_written(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Encode and write a batch.
+    ///
+    /// # Arguments
+    /// * `batch` - The Arrow [`RecordBatch`] to write, containing a geometry column.
+    ///
+    /// # Returns
+    /// `()` once the batch has been handed to the Parquet writer.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::IoError`] if the encoder or file cannot be created, or if
+    /// the batch cannot be encoded or written.
+    pub(crate) fn write(&mut self, batch: &RecordBatch) -> Result<(), N3gbError> {
+        if self.inner.is_none() {
+            let writer_options = GeoParquetWriterOptionsBuilder::default()
+                .set_encoding(GeoParquetWriterEncoding::WKB)
+                .build();
+            let encoder = GeoParquetRecordBatchEncoder::try_new(&batch.schema(), &writer_options)
+                .map_err(|e| N3gbError::IoError(e.to_string()))?;
+            let writer =
+                ArrowWriter::try_new(File::create(&self.path)?, encoder.target_schema(), None)?;
+            self.inner = Some((encoder, writer));
+        }
+
+        if let Some((encoder, writer)) = self.inner.as_mut() {
+            let encoded_batch = encoder
+                .encode_record_batch(batch)
+                .map_err(|e| N3gbError::IoError(e.to_string()))?;
+            writer.write(&encoded_batch)?;
+        }
+        Ok(())
+    }
+
+    /// Write the GeoParquet metadata and close the file.
+    ///
+    /// # Returns
+    /// `()` once the file has been finalized.
+    ///
+    /// # Errors
+    /// Returns [`N3gbError::IoError`] if no batch was written, or if the metadata
+    /// cannot be produced or the file cannot be finalized.
+    pub(crate) fn finish(self) -> Result<(), N3gbError> {
+        let (encoder, mut writer) = self.inner.ok_or_else(|| {
+            N3gbError::IoError("No record batches were written to GeoParquet".to_string())
+        })?;
+
+        let kv_metadata = encoder
+            .into_keyvalue()
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+        writer.append_key_value_metadata(kv_metadata);
+        writer.finish()?;
+        Ok(())
+    }
+}
+
 /// Trait for writing collections of [`HexCell`]s directly to GeoParquet.
 ///
 /// Implemented for any type that dereferences to `[HexCell]` (e.g. `Vec<HexCell>`, `&[HexCell]`).
@@ -76,6 +418,26 @@ pub trait HexCellsToGeoParquet: HexCellsToArrow {
     /// GeoParquet file cannot be encoded or written (via `From<ArrowError>` /
     /// `From<ParquetError>` / `From<io::Error>`).
     fn to_geoparquet(&self, path: impl AsRef<Path>) -> Result<(), N3gbError>;
+    /// Writes cells to a GeoParquet file at the given path using the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Filesystem path where the GeoParquet file is written.
+    /// * `options` - Writer options, such as the geometry encoding.
+    ///
+    /// # Returns
+    ///
+    /// `()` on success, after the cells have been written to the GeoParquet file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the record batch cannot be built or if the
+    /// GeoParquet file cannot be encoded or written.
+    fn to_geoparquet_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: &GeoParquetOptions,
+    ) -> Result<(), N3gbError>;
 }
 
 impl<T: AsRef<[HexCell]>> HexCellsToGeoParquet for T {
@@ -83,6 +445,15 @@ impl<T: AsRef<[HexCell]>> HexCellsToGeoParquet for T {
         let batch = self.to_record_batch()?;
         write_geoparquet(&batch, path)
     }
+
+    fn to_geoparquet_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: &GeoParquetOptions,
+    ) -> Result<(), N3gbError> {
+        let batch = self.to_record_batch()?;
+        write_geoparquet_with_options(&batch, path, options)
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +478,114 @@ mod tests {
         assert!(metadata.len() > 0);
         Ok(())
     }
+
+    #[test]
+    fn test_cells_to_geoparquet_native_encoding() -> Result<(), N3gbError> {
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+            HexCell::from_bng(&(383760.0, 398340.0), 12)?,
+        ];
+
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let path = dir.path().join("native.parquet");
+
+        let options = GeoParquetOptions::new().encoding(GeoParquetEncoding::Native);
+        cells.to_geoparquet_with_options(&path, &options)?;
+
+        let file = File::open(&path)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+        assert_eq!(builder.metadata().file_metadata().num_rows(), 3);
+
+        let rows: usize = builder
+            .build()?
+            .map(|batch| batch.map(|b| b.num_rows()))
+            .sum::<Result<usize, _>>()?;
+        assert_eq!(rows, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lines_to_geoparquet() -> Result<(), N3gbError> {
+        use geo_types::line_string;
+
+        let first = line_string![(x: 383000.0, y: 398000.0), (x: 385000.0, y: 399000.0)];
+        let second = line_string![(x: 385000.0, y: 399000.0), (x: 386000.0, y: 401000.0)];
+
+        let mut expected: Vec<String> = Vec::new();
+        for line in [&first, &second] {
+            for cell in HexCell::from_line_string_bng(line, 12)? {
+                if !expected.contains(&cell.id) {
+                    expected.push(cell.id);
+                }
+            }
+        }
+
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let path = dir.path().join("lines.parquet");
+        let written = lines_to_geoparquet([first, second], 12, Crs::Bng, &path)?;
+        assert_eq!(written, expected.len());
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path)?)?;
+        assert!(
+            GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata()).is_some()
+        );
+        let mut ids: Vec<String> = Vec::new();
+        for batch in builder.build()? {
+            let batch = batch?;
+            let column = batch
+                .column_by_name("id")
+                .expect("id column")
+                .as_any()
+                .downcast_ref::<arrow_array::StringArray>()
+                .expect("string ids");
+            ids.extend(column.iter().map(|id| id.unwrap().to_string()));
+        }
+        assert_eq!(ids, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_geoparquet() -> Result<(), N3gbError> {
+        let first = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+        let second = vec![
+            HexCell::from_bng(&(390000.0, 400000.0), 12)?,
+            HexCell::from_bng(&(390060.0, 400040.0), 12)?,
+            HexCell::from_bng(&(390120.0, 400080.0), 12)?,
+        ];
+
+        let dir = tempdir().map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let path = dir.path().join("append.parquet");
+
+        append_geoparquet(&first.to_record_batch()?, &path)?;
+        append_geoparquet(&second.to_record_batch()?, &path)?;
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path)?)?;
+        assert_eq!(builder.metadata().file_metadata().num_rows(), 5);
+        assert_eq!(builder.metadata().num_row_groups(), 2);
+
+        let geo = GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata())
+            .expect("geo metadata")
+            .map_err(|e| N3gbError::IoError(e.to_string()))?;
+        let bbox = geo.columns["geometry"].bbox.clone().expect("bbox");
+        assert!(bbox[0] < 383640.0 && bbox[3] > 400080.0);
+
+        let rows: usize = builder
+            .build()?
+            .map(|batch| batch.map(|b| b.num_rows()))
+            .sum::<Result<usize, _>>()?;
+        assert_eq!(rows, 5);
+
+        let ids: arrow_array::ArrayRef =
+            std::sync::Arc::new(arrow_array::StringArray::from(vec!["a", "b"]));
+        let mismatched = first.to_record_batch_with(&[("extra", ids)])?;
+        assert!(matches!(
+            append_geoparquet(&mismatched, &path),
+            Err(N3gbError::IoError(_))
+        ));
+        Ok(())
+    }
 }
@@ -89,26 +89,60 @@
 //! | Concept                  | n3gb-rs                                  |
 //! | :----------------------- | :--------------------------------------- |
 //! | Point to cell (BNG)      | `HexCell::from_bng`                      |
+//! | Point to cell ID (BNG)   | `id_for_bng`                             |
 //! | Point to cell (WGS84)    | `HexCell::from_wgs84`                    |
+//! | Point to cell (lat, lon) | `HexCell::from_lat_lon`                  |
+//! | Geohash to cell          | `HexCell::from_geohash`                  |
+//! | Point to cell + offset   | `HexCell::from_bng_with_offset`          |
+//! | Point to cell + margin   | `HexCell::from_bng_with_stability`       |
+//! | Point to cells (zooms)   | `HexCell::from_bng_multi`                |
+//! | WGS84 to cells (zooms)   | `HexCell::from_wgs84_multi`              |
 //! | Geometry to cells        | `HexCell::from_geometry`                 |
 //! | Cell ID to cell          | `HexCell::from_hex_id`                   |
+//! | Cell from stored parts   | `HexCell::from_parts`                    |
 //! | Generate cell ID         | `generate_hex_identifier`                |
+//! | Generate metre cell ID   | `generate_hex_identifier_with_precision` |
+//! | Generate unchecked ID    | `generate_hex_identifier_unchecked`      |
 //! | Decode cell ID           | `decode_hex_identifier`                  |
+//! | Decode many cell IDs     | `decode_identifiers`                     |
+//! | Unpack packed key        | `unpack_key`                             |
 //! | Point to row/col         | `point_to_row_col`                       |
 //! | Row/col to center        | `row_col_to_center`                      |
+//! | Row/col to center (fast) | `row_col_to_center_unchecked`            |
+//! | Zoom level metrics       | `zoom_table`                             |
+//! | Zoom for a line          | `suggest_zoom_for_line`                  |
+//! | Pre-build PROJ objects   | `warmup_projection`                      |
+//! | Check PROJ is usable     | `projection_available`                   |
 //!
 //! ### Cell inspection functions
 //!
 //! | Concept                  | n3gb-rs                                  |
 //! | :----------------------- | :--------------------------------------- |
-//! | Get zoom level           | `cell.zoom_level` (field)                |
+//! | Get zoom level           | `cell.zoom()`                            |
 //! | Get cell ID              | `cell.id` (field)                        |
 //! | Get center point         | `cell.center` (field)                    |
 //! | Get easting              | `cell.easting()`                         |
 //! | Get northing             | `cell.northing()`                        |
-//! | Get row index            | `cell.row` (field)                       |
-//! | Get column index         | `cell.col` (field)                       |
+//! | Get scaled ID coords     | `cell.scaled_coords()`                   |
+//! | OS 100km grid square     | `cell.os_tile()`                         |
+//! | Get row index            | `cell.row()`                             |
+//! | Get column index         | `cell.col()`                             |
 //! | Cell to polygon          | `cell.to_polygon()`                      |
+//! | Cell to scaled polygon   | `cell.to_polygon_scaled(scale)`          |
+//! | Cell to CCW polygon      | `cell.to_polygon_oriented()`             |
+//! | Cell bounding box        | `cell.bounding_box()`                    |
+//! | Cell as Geometry         | `cell.geometry()`                        |
+//! | Cell contains geometry   | `cell.contains_geometry(&geom)`          |
+//! | Cell packed key          | `cell.packed_key()`                      |
+//! | Stable display color     | `cell.color_hex()`                       |
+//! | Cell to WKB              | `cell.to_wkb()`                          |
+//! | Cell to triangle fan     | `cell.to_triangles()`                    |
+//! | Approximate equality     | `cell.approx_eq(&other, tol_m)`          |
+//! | Neighbour test           | `cell.is_adjacent(&other)`               |
+//! | Neighbour in direction   | `cell.neighbor(HexDirection::E)`         |
+//! | Bearing between cells    | `cell.bearing_to(&other)`                |
+//! | Geodesic distance        | `cell.geodesic_distance_m(&other)`       |
+//! | Debug summary            | `cell.describe()`                        |
 //!
 //! ### Grid functions
 //!
@@ -119,20 +153,57 @@
 //! | Grid from rect            | `HexGrid::from_rect`                    |
 //! | Grid from polygon (BNG)   | `HexGrid::from_bng_polygon`             |
 //! | Grid from polygon (WGS84) | `HexGrid::from_wgs84_polygon`           |
+//! | Grid from GeoJSON string  | `HexGrid::from_geojson_str`             |
+//! | Grid from geometry        | `HexGrid::from_bng_geometry`            |
+//! | Iterate polygon cells     | `HexGrid::iter_bng_polygon_cells`       |
 //! | Grid from multipolygon    | `HexGrid::from_bng_multipolygon`        |
+//! | Grid over all of GB       | `HexGrid::national`                     |
+//! | Grid from center + rings  | `HexGrid::from_center_bng`              |
+//! | Grid from saved spec      | `GridSpec::build`                       |
+//! | Cell IDs for extent       | `ids_for_extent`                        |
+//! | Tiles of cells            | `tiles`                                 |
 //! | Grid builder              | `HexGridBuilder`                        |
+//! | Builder coverage mode     | `HexGridBuilder::coverage`              |
+//! | Builder non-empty cover   | `HexGridBuilder::guarantee_nonempty`    |
+//! | Builder edge cells        | `HexGridBuilder::include_edge_cells`    |
 //! | Get cells                 | `HexGrid::cells`                        |
 //! | Get cell count            | `HexGrid::len`                          |
+//! | Parallel cell iterator    | `HexGrid::par_iter`                     |
 //! | Find cell at point        | `HexGrid::get_cell_at`                  |
 //! | Filter cells              | `HexGrid::filter`                       |
+//! | Filter cells lazily       | `HexGrid::iter_filter`                  |
+//! | Filter into new grid      | `HexGrid::par_filter`                   |
+//! | Clip grid to polygon      | `HexGrid::clip_to_bng_polygon`          |
+//! | Deterministic subsample   | `HexGrid::sample`                       |
+//! | Partition by key          | `HexGrid::partition_by`                 |
+//! | Cells on polygon boundary | `HexGrid::boundary_cells`               |
+//! | Cells touching a buffer   | `HexGrid::cells_intersecting_buffer`    |
+//! | Split value by overlap    | `HexGrid::disaggregate`                 |
+//! | Adjacency edge list       | `HexGrid::adjacency`                    |
+//! | Neighbour map by ID       | `HexGrid::neighbor_map`                 |
+//! | Seam between two grids    | `HexGrid::shared_boundary`              |
 //! | Grid to polygons          | `HexGrid::to_polygons`                  |
+//! | Cells with polygons       | `HexGrid::cells_with_polygons`          |
+//! | Convex hull of centers    | `HexGrid::convex_hull`                  |
+//! | Simplified GeoJSON        | `HexGrid::to_geojson_simplified`        |
+//! | GeoJSON with properties   | `HexGrid::to_geojson_with`              |
+//! | Generic cell source       | `CellSource::cells`                     |
+//! | Geometries to cells       | `CellsExt::to_hex_cells`                |
+//! | Index existing cells      | `HexIndex::new`                         |
+//! | Look up cell by ID        | `HexIndex::get_by_id`                   |
+//! | Indexed cell at point     | `HexIndex::get_at_point`                |
+//! | Nearest indexed cell      | `HexIndex::nearest`                     |
 //!
 //! ### Line coverage functions
 //!
 //! | Concept                  | n3gb-rs                                  |
 //! | :----------------------- | :--------------------------------------- |
 //! | Line to cells (BNG)      | `HexCell::from_line_string_bng`          |
+//! | Line cells + chainage    | `HexCell::from_line_string_bng_measured` |
+//! | Dedup cells across lines | `HexCell::from_line_string_bng_deduped`  |
 //! | Line to cells (WGS84)    | `HexCell::from_line_string_wgs84`        |
+//! | Count cells on a line    | `HexCell::count_line_cells_bng`          |
+//! | Snap line to centers     | `HexCell::snap_line_to_centers_bng`      |
 //!
 //! WGS84 input is reprojected to BNG internally; reach it via the `from_wgs84*`
 //! constructors or by setting [`Crs::Wgs84`]. There is no public standalone
@@ -168,16 +239,47 @@
 //! | Grid to Arrow points     | `HexGrid::to_arrow_points`               |
 //! | Grid to Arrow polygons   | `HexGrid::to_arrow_polygons`             |
 //! | Grid to RecordBatch      | `HexGrid::to_record_batch`               |
+//! | Cells + extra columns    | `cells.to_record_batch_with(&extra)`     |
+//! | Cells to chosen columns  | `cells.to_record_batch_fields(&fields)`  |
 //! | Grid to GeoParquet       | `HexGrid::to_geoparquet`                 |
 //! | Write GeoParquet         | `write_geoparquet`                       |
+//! | Write with encoding      | `write_geoparquet_with_options`          |
+//! | Append to GeoParquet     | `append_geoparquet`                      |
+//! | Lines to GeoParquet      | `lines_to_geoparquet`                    |
+//!
+//! ### GeoJSON I/O functions
+//!
+//! | Concept                  | n3gb-rs                                  |
+//! | :----------------------- | :--------------------------------------- |
+//! | Cells to GeoJSON value   | `cells.to_geojson_value()`               |
+//! | Cells to GeoJSON string  | `cells.to_geojson_string()`              |
+//! | Cells to GeoJSONL file   | `cells.to_geojsonl_file(path)`           |
+//!
+//! ### H3 interop functions (`h3` feature)
+//!
+//! | Concept                  | n3gb-rs                                  |
+//! | :----------------------- | :--------------------------------------- |
+//! | Cell center to H3 index  | `cell.to_h3(resolution)`                 |
+//! | Center inside H3 cell    | `cell.is_within_h3(h3_index)`            |
+//!
+//! ### Dense array functions (`ndarray` feature)
+//!
+//! | Concept                  | n3gb-rs                                  |
+//! | :----------------------- | :--------------------------------------- |
+//! | Grid to dense 2D array   | `grid.to_dense_grid()`                   |
 //!
 //! ### CSV I/O functions
 //!
 //! | Concept                  | n3gb-rs                                  |
 //! | :----------------------- | :--------------------------------------- |
 //! | CSV to hex-indexed CSV   | `csv_to_hex_csv`                         |
+//! | CSV to GeoParquet        | `csv_to_geoparquet`                      |
+//! | Dry-run CSV check        | `csv_validate`                           |
+//! | Hex IDs to geometry CSV  | `csv_expand_hex`                         |
 //! | CSV config (geometry)    | `CsvHexConfig::new`                      |
 //! | CSV config (coords)      | `CsvHexConfig::from_coords`              |
+//! | CSV config (geom cols)   | `CsvHexConfig::from_geometry_columns`    |
+//! | CSV input encoding       | `CsvHexConfig::encoding`                 |
 //!
 //! ### Constants
 //!
@@ -188,34 +290,52 @@
 //! | Cell widths by zoom      | `CELL_WIDTHS`                            |
 //! | Grid extents (BNG)       | `GRID_EXTENTS`                           |
 //! | Identifier version       | `IDENTIFIER_VERSION`                     |
+//! | Identifier length        | `IDENTIFIER_LENGTH`                      |
 
 mod cell;
 mod coord;
+#[cfg(feature = "ndarray")]
+mod dense;
 mod dimensions;
 mod error;
 mod geom;
 mod grid;
+#[cfg(feature = "h3")]
+mod h3;
+mod hex_index;
 mod index;
 mod io;
+mod parallel;
+mod source;
+mod spec;
 
-pub use cell::HexCell;
-pub use coord::{ConversionMethod, Coordinate, Crs};
+pub use cell::{HexCell, LineCellDeduper};
+pub use coord::{ConversionMethod, Coordinate, Crs, projection_available, warmup_projection};
 pub use dimensions::{
     HexagonDims, bounding_box, from_across_corners, from_across_flats, from_apothem, from_area,
     from_circumradius, from_side,
 };
 pub use error::N3gbError;
-pub use grid::{HexGrid, HexGridBuilder};
+pub use grid::{CoverageMode, HexGrid, HexGridBuilder, id_for_bng, ids_for_extent, tiles};
+pub use hex_index::HexIndex;
 pub use index::{
-    CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, IDENTIFIER_VERSION, MAX_ZOOM_LEVEL,
-    decode_hex_identifier, generate_hex_identifier, point_to_row_col, row_col_to_center,
+    CELL_RADIUS, CELL_WIDTHS, GRID_EXTENTS, HexDirection, IDENTIFIER_LENGTH,
+    IDENTIFIER_LENGTH_METRE, IDENTIFIER_LENGTH_UNCHECKED, IDENTIFIER_VERSION,
+    IDENTIFIER_VERSION_METRE, IDENTIFIER_VERSION_UNCHECKED, IdentifierPrecision, MAX_GRID_CELLS,
+    MAX_ZOOM_LEVEL, ZoomInfo, decode_hex_identifier, decode_identifiers, generate_hex_identifier,
+    generate_hex_identifier_unchecked, generate_hex_identifier_with_precision, point_to_row_col,
+    row_col_to_center, row_col_to_center_unchecked, suggest_zoom_for_line, unpack_key, zoom_table,
 };
 pub use io::{
-    CoordinateSource, CsvHexConfig, GeometryFormat, HexCellsToArrow, HexCellsToGeoParquet,
-    csv_to_hex_csv, write_geoparquet,
+    CoordinateSource, CsvEncoding, CsvHexConfig, GeoParquetEncoding, GeoParquetOptions,
+    GeometryFormat, HexCellsToArrow, HexCellsToGeoJson, HexCellsToGeoParquet, RecordBatchFields,
+    append_geoparquet, csv_expand_hex, csv_to_geoparquet, csv_to_hex_csv, csv_validate,
+    lines_to_geoparquet, write_geoparquet, write_geoparquet_with_options,
 };
 
 pub use geom::{create_hexagon, parse_geometry};
+pub use source::{CellSource, CellsExt};
+pub use spec::GridSpec;
 
 pub use geo_types;
 pub use geoarrow_array;
@@ -0,0 +1,50 @@
+//! Iterator entry points that run on rayon when the `parallel` feature is enabled
+//! and fall back to plain sequential iterators otherwise.
+//!
+//! Call sites import `crate::parallel::*` and use `par_iter` / `into_par_iter`
+//! exactly as they would with `rayon::prelude::*`.
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::prelude::*;
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) use sequential::*;
+
+#[cfg(not(feature = "parallel"))]
+mod sequential {
+    /// Sequential stand-in for `rayon::iter::IntoParallelIterator`.
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        /// Returns the ordinary owning iterator.
+        ///
+        /// # Returns
+        /// The value's [`IntoIterator::IntoIter`].
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<I: IntoIterator> IntoParallelIterator for I {}
+
+    /// Sequential stand-in for `rayon::iter::IntoParallelRefIterator`.
+    pub(crate) trait IntoParallelRefIterator<'data> {
+        /// The borrowing iterator type.
+        type Iter: Iterator;
+
+        /// Returns the ordinary borrowing iterator.
+        ///
+        /// # Returns
+        /// The iterator produced by iterating `&self`.
+        fn par_iter(&'data self) -> Self::Iter;
+    }
+
+    impl<'data, I: 'data + ?Sized> IntoParallelRefIterator<'data> for I
+    where
+        &'data I: IntoIterator,
+    {
+        type Iter = <&'data I as IntoIterator>::IntoIter;
+
+        fn par_iter(&'data self) -> Self::Iter {
+            self.into_iter()
+        }
+    }
+}
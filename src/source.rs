@@ -0,0 +1,197 @@
+use crate::cell::HexCell;
+use crate::coord::{Crs, preferred_method};
+use crate::error::N3gbError;
+use crate::grid::HexGrid;
+use geo_types::Geometry;
+
+/// Anything that can yield a sequence of [`HexCell`]s.
+///
+/// The trait is object safe, so generic consumers such as export utilities can
+/// accept `&dyn CellSource` and work with a single cell, a slice of cells, or a
+/// whole [`HexGrid`] alike.
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{CellSource, HexCell, HexGrid};
+///
+/// fn count(source: &dyn CellSource) -> usize {
+///     source.cells().count()
+/// }
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+/// let grid = HexGrid::from_bng_extent(&(383000.0, 398000.0), &(384000.0, 399000.0), 10)?;
+///
+/// assert_eq!(count(&cell), 1);
+/// assert_eq!(count(&grid), grid.len());
+/// # Ok(())
+/// # }
+/// ```
+pub trait CellSource {
+    /// Iterate over the cells of this source.
+    ///
+    /// # Returns
+    /// A boxed iterator yielding owned copies of each cell.
+    fn cells(&self) -> Box<dyn Iterator<Item = HexCell> + '_>;
+}
+
+impl CellSource for HexCell {
+    fn cells(&self) -> Box<dyn Iterator<Item = HexCell> + '_> {
+        Box::new(std::iter::once(self.clone()))
+    }
+}
+
+impl CellSource for &[HexCell] {
+    fn cells(&self) -> Box<dyn Iterator<Item = HexCell> + '_> {
+        Box::new(self.iter().cloned())
+    }
+}
+
+impl CellSource for Vec<HexCell> {
+    fn cells(&self) -> Box<dyn Iterator<Item = HexCell> + '_> {
+        Box::new(self.iter().cloned())
+    }
+}
+
+impl CellSource for HexGrid {
+    fn cells(&self) -> Box<dyn Iterator<Item = HexCell> + '_> {
+        Box::new(self.iter().cloned())
+    }
+}
+
+/// Extension trait turning an iterator of geometries into a lazy iterator of cells.
+///
+/// Each geometry is converted with [`HexCell::from_geometry`] only when the
+/// iterator reaches it, so large geometry streams never need to be collected
+/// up front. WGS84 input is projected with the preferred conversion method
+/// (OSTN15 when the `ostn15` feature is enabled, PROJ otherwise).
+///
+/// # Example
+/// ```
+/// use n3gb_rs::{CellsExt, Crs, HexCell};
+/// use n3gb_rs::geo_types::{Geometry, Point};
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let geoms = vec![
+///     Geometry::Point(Point::new(383640.0, 398260.0)),
+///     Geometry::Point(Point::new(457500.0, 340000.0)),
+/// ];
+/// let cells: Vec<HexCell> = geoms
+///     .into_iter()
+///     .to_hex_cells(10, Crs::Bng)
+///     .collect::<Result<_, _>>()?;
+/// assert_eq!(cells.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub trait CellsExt: Iterator<Item = Geometry<f64>> + Sized {
+    /// Lazily converts each geometry into its cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `zoom_level` - The zoom level (0-15) at which to generate cells.
+    /// * `crs` - The coordinate reference system of the input geometries.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `Ok(cell)` for every cell of every geometry, in
+    /// input order. A geometry that fails to convert yields a single `Err` in
+    /// its place, and iteration continues with the next geometry.
+    fn to_hex_cells(
+        self,
+        zoom_level: u8,
+        crs: Crs,
+    ) -> impl Iterator<Item = Result<HexCell, N3gbError>> {
+        let method = preferred_method();
+        self.flat_map(move |geom| {
+            let (cells, err) = match HexCell::from_geometry(geom, zoom_level, crs, method) {
+                Ok(cells) => (cells, None),
+                Err(e) => (Vec::new(), Some(e)),
+            };
+            cells.into_iter().map(Ok).chain(err.map(Err))
+        })
+    }
+}
+
+impl<I: Iterator<Item = Geometry<f64>>> CellsExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{LineString, Point, Polygon};
+
+    fn collect_ids(source: &dyn CellSource) -> Vec<String> {
+        source.cells().map(|cell| cell.id).collect()
+    }
+
+    #[test]
+    fn test_cell_source_for_cell() -> Result<(), N3gbError> {
+        let cell = HexCell::from_bng(&(383640.0, 398260.0), 10)?;
+        assert_eq!(collect_ids(&cell), vec![cell.id.clone()]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cell_source_for_slice_and_vec() -> Result<(), N3gbError> {
+        let cells = vec![
+            HexCell::from_bng(&(383640.0, 398260.0), 12)?,
+            HexCell::from_bng(&(383700.0, 398300.0), 12)?,
+        ];
+        let expected: Vec<String> = cells.iter().map(|c| c.id.clone()).collect();
+
+        let slice: &[HexCell] = &cells;
+        assert_eq!(collect_ids(&slice), expected);
+        assert_eq!(collect_ids(&cells), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cell_source_for_grid() -> Result<(), N3gbError> {
+        let grid = HexGrid::from_bng_extent(&(383000.0, 398000.0), &(384000.0, 399000.0), 10)?;
+        let expected: Vec<String> = grid.iter().map(|c| c.id.clone()).collect();
+
+        assert!(!expected.is_empty());
+        assert_eq!(collect_ids(&grid), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cells_ext_mixed_geometries() -> Result<(), N3gbError> {
+        let line = LineString::from(vec![(383000.0, 398000.0), (384000.0, 398000.0)]);
+        let square = Polygon::new(
+            LineString::from(vec![
+                (457000.0, 339000.0),
+                (458000.0, 339000.0),
+                (458000.0, 340000.0),
+                (457000.0, 340000.0),
+                (457000.0, 339000.0),
+            ]),
+            vec![],
+        );
+        let geoms = vec![
+            Geometry::Point(Point::new(383640.0, 398260.0)),
+            Geometry::LineString(line.clone()),
+            Geometry::Polygon(square),
+        ];
+
+        let cells: Vec<HexCell> = geoms
+            .into_iter()
+            .to_hex_cells(10, Crs::Bng)
+            .collect::<Result<_, _>>()?;
+
+        let line_cells = HexCell::from_line_string_bng(&line, 10)?;
+        assert_eq!(cells.len(), 1 + line_cells.len() + 1);
+        assert_eq!(cells[0], HexCell::from_bng(&(383640.0, 398260.0), 10)?);
+        assert_eq!(cells[1..=line_cells.len()], line_cells[..]);
+
+        let results: Vec<_> = vec![Geometry::Point(Point::new(383640.0, 398260.0))]
+            .into_iter()
+            .to_hex_cells(16, Crs::Bng)
+            .collect();
+        assert!(matches!(
+            results[..],
+            [Err(N3gbError::InvalidZoomLevel(16))]
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use crate::error::N3gbError;
+use crate::grid::HexGrid;
+use geo_types::{Coord, LineString, Polygon};
+use serde::{Deserialize, Serialize};
+
+/// A serializable description of how to build a [`HexGrid`].
+///
+/// Storing the spec rather than the cells keeps configuration small and lets a
+/// grid be regenerated deterministically: building the same spec always yields
+/// the same cells in the same order. Specs serialize to JSON tagged by `type`,
+/// e.g. `{"type":"extent","min_x":...,"zoom_level":10}`.
+///
+/// # Example
+///
+/// ```
+/// use n3gb_rs::GridSpec;
+///
+/// # fn main() -> Result<(), n3gb_rs::N3gbError> {
+/// let spec = GridSpec::Extent {
+///     min_x: 457000.0,
+///     min_y: 339500.0,
+///     max_x: 458000.0,
+///     max_y: 340500.0,
+///     zoom_level: 10,
+/// };
+/// let json = spec.to_json()?;
+///
+/// let grid = GridSpec::from_json(&json)?.build()?;
+/// assert!(!grid.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GridSpec {
+    /// A BNG bounding box, built with [`HexGrid::from_bng_extent`].
+    Extent {
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+        zoom_level: u8,
+    },
+    /// A BNG polygon, built with [`HexGrid::from_bng_polygon`]. Rings are
+    /// lists of `[x, y]` positions.
+    BngPolygon {
+        exterior: Vec<[f64; 2]>,
+        #[serde(default)]
+        interiors: Vec<Vec<[f64; 2]>>,
+        zoom_level: u8,
+    },
+    /// A disk of rings around a BNG point, built with [`HexGrid::from_center_bng`].
+    Center {
+        x: f64,
+        y: f64,
+        zoom_level: u8,
+        k_rings: u32,
+    },
+    /// The whole BNG grid, built with [`HexGrid::national`].
+    National { zoom_level: u8 },
+}
+
+impl GridSpec {
+    /// Creates a polygon spec from a BNG polygon.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - The polygon, in BNG (EPSG:27700) coordinates.
+    /// * `zoom_level` - The zoom level for the generated cells.
+    ///
+    /// # Returns
+    ///
+    /// A [`GridSpec::BngPolygon`] holding the polygon's rings.
+    pub fn bng_polygon(polygon: &Polygon<f64>, zoom_level: u8) -> Self {
+        let ring = |line: &LineString<f64>| line.coords().map(|c| [c.x, c.y]).collect();
+        GridSpec::BngPolygon {
+            exterior: ring(polygon.exterior()),
+            interiors: polygon.interiors().iter().map(ring).collect(),
+            zoom_level,
+        }
+    }
+
+    /// Returns the zoom level the spec builds at.
+    pub fn zoom_level(&self) -> u8 {
+        match self {
+            GridSpec::Extent { zoom_level, .. }
+            | GridSpec::BngPolygon { zoom_level, .. }
+            | GridSpec::Center { zoom_level, .. }
+            | GridSpec::National { zoom_level } => *zoom_level,
+        }
+    }
+
+    /// Serializes the spec to a JSON string.
+    ///
+    /// # Returns
+    ///
+    /// The spec as a JSON object tagged with its `type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the spec cannot be serialized, e.g.
+    /// because a coordinate is not finite.
+    pub fn to_json(&self) -> Result<String, N3gbError> {
+        serde_json::to_string(self).map_err(|e| N3gbError::IoError(e.to_string()))
+    }
+
+    /// Parses a spec from a JSON string produced by [`GridSpec::to_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON text.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `GridSpec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`N3gbError::IoError`] if the text is not a valid spec.
+    pub fn from_json(json: &str) -> Result<Self, N3gbError> {
+        serde_json::from_str(json).map_err(|e| N3gbError::IoError(e.to_string()))
+    }
+
+    /// Builds the grid the spec describes.
+    ///
+    /// # Returns
+    ///
+    /// The generated [`HexGrid`].
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error from the underlying [`HexGrid`] constructor, such
+    /// as [`N3gbError::InvalidZoomLevel`] or [`N3gbError::TooManyCells`].
+    pub fn build(&self) -> Result<HexGrid, N3gbError> {
+        match self {
+            GridSpec::Extent {
+                min_x,
+                min_y,
+                max_x,
+                max_y,
+                zoom_level,
+            } => HexGrid::from_bng_extent(&(*min_x, *min_y), &(*max_x, *max_y), *zoom_level),
+            GridSpec::BngPolygon {
+                exterior,
+                interiors,
+                zoom_level,
+            } => {
+                let ring = |positions: &Vec<[f64; 2]>| {
+                    LineString::from(
+                        positions
+                            .iter()
+                            .map(|&[x, y]| Coord { x, y })
+                            .collect::<Vec<_>>(),
+                    )
+                };
+                let polygon = Polygon::new(ring(exterior), interiors.iter().map(ring).collect());
+                HexGrid::from_bng_polygon(&polygon, *zoom_level)
+            }
+            GridSpec::Center {
+                x,
+                y,
+                zoom_level,
+                k_rings,
+            } => HexGrid::from_center_bng(&(*x, *y), *zoom_level, *k_rings),
+            GridSpec::National { zoom_level } => HexGrid::national(*zoom_level),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::polygon;
+
+    fn ids(grid: &HexGrid) -> Vec<&str> {
+        grid.iter().map(|cell| cell.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_extent_spec_round_trip() -> Result<(), N3gbError> {
+        let spec = GridSpec::Extent {
+            min_x: 457000.0,
+            min_y: 339500.0,
+            max_x: 458000.25,
+            max_y: 340500.0,
+            zoom_level: 10,
+        };
+
+        let json = spec.to_json()?;
+        assert!(json.contains("\"type\":\"extent\""));
+        let parsed = GridSpec::from_json(&json)?;
+        assert_eq!(parsed, spec);
+
+        let rebuilt = parsed.build()?;
+        let direct = HexGrid::from_bng_extent(&(457000.0, 339500.0), &(458000.25, 340500.0), 10)?;
+        assert_eq!(rebuilt.zoom_level(), 10);
+        assert_eq!(ids(&rebuilt), ids(&direct));
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_and_center_specs() -> Result<(), N3gbError> {
+        let polygon = polygon![
+            (x: 457000.0, y: 339500.0),
+            (x: 458000.0, y: 339500.0),
+            (x: 457500.0, y: 340500.0),
+            (x: 457000.0, y: 339500.0),
+        ];
+        let spec = GridSpec::from_json(&GridSpec::bng_polygon(&polygon, 11).to_json()?)?;
+        assert_eq!(spec.zoom_level(), 11);
+        assert_eq!(
+            ids(&spec.build()?),
+            ids(&HexGrid::from_bng_polygon(&polygon, 11)?)
+        );
+
+        let spec = GridSpec::from_json(
+            r#"{"type":"center","x":383640.0,"y":398260.0,"zoom_level":10,"k_rings":2}"#,
+        )?;
+        assert_eq!(spec.build()?.len(), 19);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_spec_json() {
+        assert!(matches!(
+            GridSpec::from_json(r#"{"type":"hexagon","zoom_level":10}"#),
+            Err(N3gbError::IoError(_))
+        ));
+    }
+}